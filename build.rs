@@ -1,6 +1,8 @@
 extern crate version_check;
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(nightly)");
+
     if let Some(true) = version_check::supports_features() {
         println!("cargo:rustc-cfg=nightly");
     }
@@ -1,6 +1,13 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
 use core::str::{self, Utf8Error};
 
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
 #[cfg(feature = "std")]
 use std::ffi::{CStr, OsStr};
 
@@ -8,8 +15,19 @@ use std::ffi::{CStr, OsStr};
 use std::path::Path;
 
 use c_char;
+#[cfg(feature = "std")]
+use c_utf8_buf::CUtf8Buf;
+use c_utf8_index::CUtf8Index;
+use c_utf8_strict::CUtf8Strict;
+use copied::Copied;
 use error::Error;
-use ext::Ext;
+use ext::{self, Ext};
+use pattern::Pattern;
+
+#[cfg(not(feature = "std"))]
+extern "C" {
+    fn strlen(cs: *const c_char) -> usize;
+}
 
 /// Like [`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html), except
 /// with the guarantee of being encoded as valid [UTF-8].
@@ -30,11 +48,65 @@ use ext::Ext;
 ///   [`str`](https://doc.rust-lang.org/std/primitive.str.html) strings with
 ///   ease.
 ///
+/// # Reading
+///
+/// `CUtf8` derefs to the [`str`] it contains, without the trailing nul byte.
+/// This makes all of the read-only methods of `str` available directly:
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// let s = c_utf8!("key=value");
+///
+/// assert!(s.starts_with("key"));
+/// assert_eq!(s.find('='), Some(3));
+/// assert_eq!(s.chars().rev().next(), Some('e'));
+///
+/// fn takes_str(s: &str) -> usize { s.len() }
+/// assert_eq!(takes_str(s), 9);
+/// # }
+/// ```
+///
+/// Methods that return the end of the string, such as
+/// [`strip_prefix`](#method.strip_prefix) and
+/// [`split_once`](#method.split_once), return it as a `&CUtf8` instead. Every
+/// suffix of a C string is nul-terminated, so it can be passed to C without
+/// copying.
+///
+/// # Comparison
+///
+/// Equality, ordering and hashing only consider the string before the trailing
+/// nul byte. These are the same as for the corresponding [`str`], which allows
+/// for looking up C string keys in maps with a `&str`:
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8; fn main() {
+/// use std::collections::HashMap;
+///
+/// let mut symbols = HashMap::<&CUtf8, usize>::new();
+/// symbols.insert(c_utf8!("main"), 0x1000);
+///
+/// assert_eq!(symbols.get("main"), Some(&0x1000));
+/// # }
+/// ```
+///
+/// C strings can also be compared directly with `str`, `String`, `CStr` and
+/// the other string types of this crate:
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// let name = c_utf8!("eth0");
+///
+/// assert_eq!(name, "eth0");
+/// assert_eq!("eth0", name);
+/// assert!(name < "eth1");
+/// # }
+/// ```
+///
 /// [UTF-8]: https://en.wikipedia.org/wiki/UTF-8
-#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+#[repr(transparent)]
 pub struct CUtf8(str);
 
-#[cfg(feature = "try_from")]
 mod try_from {
     use super::*;
     use core::convert::TryFrom;
@@ -68,6 +140,59 @@ mod try_from {
     }
 }
 
+impl Deref for CUtf8 {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for CUtf8 {
+    #[inline]
+    fn eq(&self, other: &CUtf8) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CUtf8 {}
+
+impl PartialOrd for CUtf8 {
+    #[inline]
+    fn partial_cmp(&self, other: &CUtf8) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CUtf8 {
+    #[inline]
+    fn cmp(&self, other: &CUtf8) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for CUtf8 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Borrow<str> for CUtf8 {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for &CUtf8 {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl AsRef<str> for CUtf8 {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -141,13 +266,68 @@ impl CUtf8 {
         CUtf8::from_str(str::from_utf8(bytes)?)
     }
 
+    /// Returns a C string containing `bytes` up to and including the first nul
+    /// byte, or an error if there is no nul byte or if the bytes before it are
+    /// not encoded as UTF-8.
+    ///
+    /// Anything after the first nul byte is ignored. This is useful for fixed
+    /// size buffers that may or may not use their entire length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::{CUtf8, Error};
+    ///
+    /// let name = *b"eth0\0\0\0\0";
+    /// assert_eq!(CUtf8::from_bytes_until_nul(&name).unwrap().as_str(), "eth0");
+    ///
+    /// let full = *b"eth0";
+    /// assert_eq!(CUtf8::from_bytes_until_nul(&full), Err(Error::MissingNul));
+    /// ```
+    #[inline]
+    pub fn from_bytes_until_nul(bytes: &[u8]) -> Result<&CUtf8, Error> {
+        match bytes.first_nul() {
+            Some(pos) => {
+                let s = str::from_utf8(&bytes[..=pos])?;
+                unsafe { Ok(CUtf8::from_str_unchecked(s)) }
+            },
+            None => Err(Error::MissingNul),
+        }
+    }
+
+    /// Returns a C string from a fixed-size C `char` array, up to and including
+    /// the first nul byte.
+    ///
+    /// This is the same as [`from_bytes_until_nul`](#method.from_bytes_until_nul)
+    /// for arrays of [`c_char`](type.c_char.html), such as those found in C
+    /// structs like `utsname`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::{c_char, CUtf8};
+    ///
+    /// let mut sysname = [0 as c_char; 65];
+    /// sysname[..5].copy_from_slice(&[76, 105, 110, 117, 120]);
+    ///
+    /// assert_eq!(CUtf8::from_chars_until_nul(&sysname).unwrap().as_str(), "Linux");
+    /// ```
+    #[inline]
+    pub fn from_chars_until_nul<const N: usize>(chars: &[c_char; N]) -> Result<&CUtf8, Error> {
+        use core::slice;
+
+        let bytes = unsafe { slice::from_raw_parts(chars.as_ptr() as *const u8, N) };
+        CUtf8::from_bytes_until_nul(bytes)
+    }
+
     /// Returns the UTF-8 string if it is terminated by a nul byte.
+    #[allow(clippy::should_implement_trait)]
     #[inline]
     pub fn from_str(s: &str) -> Result<&CUtf8, Error> {
         if s.is_nul_terminated() {
             unsafe { Ok(CUtf8::from_str_unchecked(s)) }
         } else {
-            Err(Error::Nul)
+            Err(Error::MissingNul)
         }
     }
 
@@ -159,7 +339,121 @@ impl CUtf8 {
         unsafe { Ok(CUtf8::from_str_unchecked(s)) }
     }
 
+    /// Returns a C string containing `bytes`, replacing invalid UTF-8
+    /// sequences with U+FFFD REPLACEMENT CHARACTER and appending a nul
+    /// terminator if needed.
+    ///
+    /// The result is borrowed when `bytes` are already valid UTF-8 ending with
+    /// a nul byte. Otherwise, a new [`CUtf8Buf`](struct.CUtf8Buf.html) is
+    /// allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use c_utf8::CUtf8;
+    ///
+    /// let valid = CUtf8::from_bytes_lossy(b"host\0");
+    /// assert!(matches!(valid, Cow::Borrowed(_)));
+    ///
+    /// let invalid = CUtf8::from_bytes_lossy(b"h\xFFst");
+    /// assert_eq!(invalid.as_str(), "h\u{FFFD}st");
+    /// assert!(matches!(invalid, Cow::Owned(_)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Cow<'_, CUtf8> {
+        match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => match CUtf8::from_str(s) {
+                Ok(c) => Cow::Borrowed(c),
+                Err(_) => Cow::Owned(CUtf8Buf::from(s)),
+            },
+            Cow::Owned(s) => Cow::Owned(CUtf8Buf::from_string(s)),
+        }
+    }
+
+    /// Returns the C string, replacing invalid UTF-8 sequences with U+FFFD
+    /// REPLACEMENT CHARACTER.
+    ///
+    /// The result is borrowed when `c` is already valid UTF-8.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_c_str_lossy(c: &CStr) -> Cow<'_, CUtf8> {
+        CUtf8::from_bytes_lossy(c.to_bytes_with_nul())
+    }
+
+    /// Returns a C string containing `bytes`, evaluable within a constant
+    /// expression.
+    ///
+    /// This allows for building `&'static CUtf8` instances from byte string
+    /// literals and [`include_bytes!`] data without `unsafe`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` are not encoded as UTF-8 or do not end with a nul
+    /// byte. When used within a constant, this is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8;
+    ///
+    /// const NAMES: &[&CUtf8] = &[
+    ///     CUtf8::from_bytes_const(b"one\0"),
+    ///     CUtf8::from_bytes_const("tw\u{f6}\0".as_bytes()),
+    /// ];
+    ///
+    /// assert_eq!(NAMES[1].as_str(), "tw\u{f6}");
+    /// ```
+    ///
+    /// Invalid input fails to compile:
+    ///
+    /// ```compile_fail
+    /// # use c_utf8::CUtf8;
+    /// const BAD: &CUtf8 = CUtf8::from_bytes_const(b"\xFF\0");
+    /// ```
+    ///
+    /// [`include_bytes!`]: https://doc.rust-lang.org/std/macro.include_bytes.html
+    pub const fn from_bytes_const(bytes: &[u8]) -> &CUtf8 {
+        let s = match str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("bytes are not encoded as UTF-8"),
+        };
+        if !ext::ends_with_nul(bytes) {
+            panic!("bytes are not terminated by a nul byte");
+        }
+        unsafe { &*(s as *const str as *const CUtf8) }
+    }
+
+    /// Returns the UTF-8 string if it is terminated by a nul byte, evaluable
+    /// within a constant expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` does not end with a nul byte. When used within a constant,
+    /// this is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8;
+    ///
+    /// const NAME: &CUtf8 = CUtf8::from_str_const("name\0");
+    ///
+    /// assert_eq!(NAME.as_str(), "name");
+    /// ```
+    pub const fn from_str_const(s: &str) -> &CUtf8 {
+        if !ext::ends_with_nul(s.as_bytes()) {
+            panic!("string is not terminated by a nul byte");
+        }
+        unsafe { &*(s as *const str as *const CUtf8) }
+    }
+
     /// Returns the raw C string if it is valid UTF-8 up to the first nul byte.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a nul-terminated C string that remains valid for
+    /// the lifetime `'a`.
     #[inline]
     pub unsafe fn from_ptr<'a>(raw: *const c_char) -> Result<&'a CUtf8, Utf8Error> {
         #[cfg(feature = "std")] {
@@ -168,16 +462,94 @@ impl CUtf8 {
         #[cfg(not(feature = "std"))] {
             use core::slice;
 
-            extern {
-                fn strlen(cs: *const c_char) -> usize;
-            }
-
             let n = strlen(raw) + 1;
             let s = str::from_utf8(slice::from_raw_parts(raw as *const u8, n))?;
             Ok(CUtf8::from_str_unchecked(s))
         }
     }
 
+    /// Returns the raw C string up to the first nul byte without checking
+    /// UTF-8 validity.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a nul-terminated C string that remains valid for
+    /// the lifetime `'a`, and the bytes up to the first nul must be valid
+    /// UTF-8.
+    #[inline]
+    pub unsafe fn from_ptr_unchecked<'a>(raw: *const c_char) -> &'a CUtf8 {
+        #[cfg(feature = "std")] {
+            CUtf8::from_c_str_unchecked(CStr::from_ptr(raw))
+        }
+        #[cfg(not(feature = "std"))] {
+            use core::slice;
+
+            let n = strlen(raw) + 1;
+            CUtf8::from_bytes_unchecked(slice::from_raw_parts(raw as *const u8, n))
+        }
+    }
+
+    /// Returns the raw C string up to the first nul byte, replacing invalid
+    /// UTF-8 sequences with U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// The result is borrowed when the string is already valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a nul-terminated C string that remains valid for
+    /// the lifetime `'a`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub unsafe fn from_ptr_lossy<'a>(raw: *const c_char) -> Cow<'a, CUtf8> {
+        CUtf8::from_c_str_lossy(CStr::from_ptr(raw))
+    }
+
+    /// Returns the raw C string if a nul byte is found within the first `max`
+    /// bytes and the string is valid UTF-8 up to it.
+    ///
+    /// Like C's `strnlen`, this never reads more than `max` bytes from `raw`,
+    /// making it suitable for buffers that may be missing a nul terminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TooLong`](enum.Error.html#variant.TooLong) if none of
+    /// the first `max` bytes is nul.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be valid for reading up to `max` bytes or up to and including
+    /// its first nul byte, whichever is shorter. That memory must remain valid
+    /// for the lifetime `'a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::{CUtf8, Error};
+    /// # use std::os::raw::c_char;
+    ///
+    /// let buf = *b"abc\0";
+    /// let ptr = buf.as_ptr() as *const c_char;
+    ///
+    /// unsafe {
+    ///     assert_eq!(CUtf8::from_ptr_bounded(ptr, 4).unwrap().as_str(), "abc");
+    ///     assert_eq!(CUtf8::from_ptr_bounded(ptr, 3), Err(Error::TooLong(3)));
+    /// }
+    /// ```
+    pub unsafe fn from_ptr_bounded<'a>(raw: *const c_char, max: usize) -> Result<&'a CUtf8, Error> {
+        use core::slice;
+
+        let raw = raw as *const u8;
+        let mut len = 0;
+        while len < max {
+            if *raw.add(len) == 0 {
+                let bytes = slice::from_raw_parts(raw, len + 1);
+                return Ok(CUtf8::from_str_unchecked(str::from_utf8(bytes)?));
+            }
+            len += 1;
+        }
+        Err(Error::TooLong(max))
+    }
+
     /// Returns the number of bytes without taking into account the trailing nul
     /// byte.
     ///
@@ -220,36 +592,358 @@ impl CUtf8 {
         self.0.len() == 1
     }
 
+    /// Returns `true` if `self` has a nul byte before its trailing one.
+    ///
+    /// C APIs stop reading at the first nul byte, so they would only see part
+    /// of such a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8; fn main() {
+    /// assert!(!c_utf8!("abc").has_interior_nul());
+    /// assert!(CUtf8::from_str("a\0bc\0").unwrap().has_interior_nul());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn has_interior_nul(&self) -> bool {
+        self.interior_nul_position().is_some()
+    }
+
+    /// Returns the byte position of the first nul byte before the trailing
+    /// one, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8;
+    ///
+    /// let s = CUtf8::from_str("ab\0c\0d\0").unwrap();
+    /// assert_eq!(s.interior_nul_position(), Some(2));
+    /// ```
+    #[inline]
+    pub fn interior_nul_position(&self) -> Option<usize> {
+        self.as_str().first_nul()
+    }
+
+    /// Returns the part of `self` that C sees: everything up to and including
+    /// the first nul byte.
+    ///
+    /// This does not copy, since the prefix is already nul-terminated. The
+    /// result is a [`CUtf8Strict`](struct.CUtf8Strict.html), which derefs to
+    /// `CUtf8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8;
+    ///
+    /// let s = CUtf8::from_str("abc\0def\0").unwrap();
+    /// let prefix: &CUtf8 = s.until_first_nul();
+    ///
+    /// assert_eq!(prefix.as_bytes_with_nul(), b"abc\0");
+    /// assert_eq!(prefix.as_ptr(), s.as_ptr());
+    /// ```
+    #[inline]
+    pub fn until_first_nul(&self) -> &CUtf8Strict {
+        let len = match self.interior_nul_position() {
+            Some(pos) => pos + 1,
+            None => self.0.len(),
+        };
+        unsafe {
+            let s = self.0.get_unchecked(..len);
+            CUtf8Strict::from_c_utf8_unchecked(CUtf8::from_str_unchecked(s))
+        }
+    }
+
+    /// Returns a subslice of `self`, or `None` if `index` is out of bounds or
+    /// not on a `char` boundary.
+    ///
+    /// Ranges that are open at the end (`n..` and `..`) return a `&CUtf8`,
+    /// since the slice keeps the trailing nul byte. Other ranges return a
+    /// `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let s = c_utf8!("héllo");
+    ///
+    /// assert_eq!(s.get(3..).unwrap().as_str(), "llo");
+    /// assert_eq!(s.get(..3), Some("hé"));
+    /// assert!(s.get(2..).is_none());
+    /// assert!(s.get(7..).is_none());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn get<I: CUtf8Index>(&self, index: I) -> Option<&I::Output> {
+        index.__get(self)
+    }
+
+    /// Divides `self` into two at a byte index, keeping the second half as a
+    /// C string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is past the end of the string or is not on a `char`
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let (head, tail) = c_utf8!("key=value").split_at(4);
+    ///
+    /// assert_eq!(head, "key=");
+    /// assert_eq!(tail.as_bytes_with_nul(), b"value\0");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (&str, &CUtf8) {
+        let (head, tail) = self.as_str().split_at(mid);
+        (head, self.suffix(tail))
+    }
+
+    /// Returns the C string after the first `n` `char`s, or an empty C string
+    /// if `self` has fewer than `n` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let s = c_utf8!("ñandú");
+    ///
+    /// assert_eq!(s.skip_chars(2).as_str(), "ndú");
+    /// assert!(s.skip_chars(9).is_empty());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn skip_chars(&self, n: usize) -> &CUtf8 {
+        let s = self.as_str();
+        match s.char_indices().nth(n) {
+            Some((i, _)) => self.suffix(&s[i..]),
+            None => self.suffix(""),
+        }
+    }
+
+    /// Returns the C string after `prefix`, or `None` if `self` does not start
+    /// with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let s = c_utf8!("lib/libc.so");
+    ///
+    /// assert_eq!(s.strip_prefix("lib/").unwrap().as_str(), "libc.so");
+    /// assert!(s.strip_prefix("usr/").is_none());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn strip_prefix<P: Pattern>(&self, prefix: P) -> Option<&CUtf8> {
+        prefix.__strip_prefix_of(self.as_str()).map(|rest| self.suffix(rest))
+    }
+
+    /// Returns the C string with leading whitespace removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// assert_eq!(c_utf8!("\t hi ").trim_start().as_str(), "hi ");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn trim_start(&self) -> &CUtf8 {
+        self.suffix(self.as_str().trim_start())
+    }
+
+    /// Returns the C string with all leading matches of `pat` removed.
+    #[inline]
+    pub fn trim_start_matches<P: Pattern>(&self, pat: P) -> &CUtf8 {
+        self.suffix(pat.__trim_start_matches_of(self.as_str()))
+    }
+
+    /// Splits `self` on the first occurrence of `delimiter`, returning the
+    /// part before it and the C string after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let (key, value) = c_utf8!("a=b=c").split_once('=').unwrap();
+    ///
+    /// assert_eq!(key, "a");
+    /// assert_eq!(value.as_str(), "b=c");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split_once<P: Pattern>(&self, delimiter: P) -> Option<(&str, &CUtf8)> {
+        delimiter.__split_once_of(self.as_str())
+            .map(|(head, tail)| (head, self.suffix(tail)))
+    }
+
+    /// Splits `self` on the last occurrence of `delimiter`, returning the
+    /// part before it and the C string after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; fn main() {
+    /// let (dir, file) = c_utf8!("/usr/lib/libc.so").rsplit_once('/').unwrap();
+    ///
+    /// assert_eq!(dir, "/usr/lib");
+    /// assert_eq!(file.as_str(), "libc.so");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn rsplit_once<P: Pattern>(&self, delimiter: P) -> Option<(&str, &CUtf8)> {
+        delimiter.__rsplit_once_of(self.as_str())
+            .map(|(head, tail)| (head, self.suffix(tail)))
+    }
+
+    /// Returns the C string that `rest`, a suffix of `self.as_str()`, starts.
+    #[inline]
+    pub(crate) fn suffix(&self, rest: &str) -> &CUtf8 {
+        debug_assert!(rest.len() <= self.len());
+        let start = self.len() - rest.len();
+        unsafe { CUtf8::from_str_unchecked(self.0.get_unchecked(start..)) }
+    }
+
     /// Returns a C string without checking UTF-8 validity or for a trailing
     /// nul byte.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8 and end with a nul byte.
     #[inline]
     pub unsafe fn from_bytes_unchecked(b: &[u8]) -> &CUtf8 {
         &*(b as *const [u8] as *const CUtf8)
     }
 
     /// Returns a C string without checking for a trailing nul byte.
+    ///
+    /// # Safety
+    ///
+    /// The string must end with a nul byte.
     #[inline]
     pub unsafe fn from_str_unchecked(s: &str) -> &CUtf8 {
         &*(s as *const str as *const CUtf8)
     }
 
     /// Returns a mutable C string without checking for a trailing nul byte.
+    ///
+    /// # Safety
+    ///
+    /// The string must end with a nul byte.
     #[inline]
     pub unsafe fn from_str_unchecked_mut(s: &mut str) -> &mut CUtf8 {
         &mut *(s as *mut str as *mut CUtf8)
     }
 
     /// Returns a C string without checking UTF-8 validity.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of `c` must be valid UTF-8.
     #[cfg(feature = "std")]
     #[inline]
     pub unsafe fn from_c_str_unchecked(c: &CStr) -> &CUtf8 {
         Self::from_bytes_unchecked(c.to_bytes_with_nul())
     }
 
+    /// Used by the [`c_utf8!`](macro.c_utf8.html) macro to ensure at compile
+    /// time that the string literal only has a nul byte at its end.
+    #[doc(hidden)]
+    pub const fn __from_literal(s: &str) -> &CUtf8 {
+        let bytes = s.as_bytes();
+        if !ext::ends_with_nul(bytes) {
+            panic!("c_utf8! string literal is missing its nul terminator");
+        }
+        let mut i = 0;
+        while i < bytes.len() - 1 {
+            if bytes[i] == 0 {
+                panic!("c_utf8! string literal contains an interior nul byte");
+            }
+            i += 1;
+        }
+        unsafe { &*(s as *const str as *const CUtf8) }
+    }
+
+    /// Copies as much of `self` as fits into `buf`, always leaving it
+    /// nul-terminated, like C's `strlcpy`.
+    ///
+    /// The string is only cut on a `char` boundary, so `buf` always holds valid
+    /// UTF-8. If `buf` is empty, nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate c_utf8; use c_utf8::c_char; fn main() {
+    /// let mut buf = [1 as c_char; 8];
+    /// let copied = c_utf8!("hello").copy_to(&mut buf);
+    ///
+    /// assert!(!copied.is_truncated());
+    /// assert_eq!(buf[5], 0);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn copy_to(&self, buf: &mut [c_char]) -> Copied {
+        use core::slice;
+
+        let len = buf.len();
+        let bytes = unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, len) };
+        self.copy_to_bytes(bytes)
+    }
+
+    /// Copies as much of `self` as fits into `buf`, always leaving it
+    /// nul-terminated.
+    ///
+    /// This is the same as [`copy_to`](#method.copy_to) for byte buffers.
+    pub fn copy_to_bytes(&self, buf: &mut [u8]) -> Copied {
+        let src = self.as_str();
+        let required = src.len() + 1;
+
+        let max = match buf.len().checked_sub(1) {
+            Some(max) => max,
+            None => return Copied { written: 0, required },
+        };
+
+        let mut written = core::cmp::min(src.len(), max);
+        while !src.is_char_boundary(written) {
+            written -= 1;
+        }
+
+        buf[..written].copy_from_slice(&src.as_bytes()[..written]);
+        buf[written] = 0;
+
+        Copied { written, required }
+    }
+
+    /// Copies as much of `self` as fits into the `len` bytes at `ptr`, always
+    /// leaving them nul-terminated.
+    ///
+    /// This is the same as [`copy_to`](#method.copy_to) for a C-style
+    /// `char *buf, size_t len` pair.
+    ///
+    /// # Safety
+    ///
+    /// If `len` is nonzero, `ptr` must be valid for writing `len` bytes and
+    /// must not overlap `self`.
+    #[inline]
+    pub unsafe fn copy_to_ptr(&self, ptr: *mut c_char, len: usize) -> Copied {
+        use core::slice;
+
+        if len == 0 {
+            return self.copy_to_bytes(&mut []);
+        }
+        self.copy_to_bytes(slice::from_raw_parts_mut(ptr as *mut u8, len))
+    }
+
     /// Returns a pointer to the start of the raw C string.
     #[inline]
-    pub fn as_ptr(&self) -> *const c_char {
-        self.as_bytes().as_ptr() as *const c_char
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.0.as_ptr() as *const c_char
     }
 
     /// Returns `self` as a normal C string.
@@ -0,0 +1,255 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::str;
+
+use c_utf8::CUtf8;
+use error::Error;
+
+/// An owned UTF-8 encoded C string stored inline, with room for up to `N - 1`
+/// bytes plus the nul terminator.
+///
+/// This type does not allocate and is available without the `std` feature.
+/// Operations that would exceed the capacity fail instead of growing.
+///
+/// `N` must be at least 1, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use c_utf8::{CUtf8Array, Error};
+///
+/// let mut name = CUtf8Array::<8>::new();
+/// write!(name, "tty{}", 3).unwrap();
+///
+/// assert_eq!(name.as_bytes_with_nul(), b"tty3\0");
+/// assert_eq!(name.push_str("long"), Err(Error::CapacityExceeded(7)));
+/// assert_eq!(name, "tty3");
+/// ```
+#[derive(Clone, Copy)]
+pub struct CUtf8Array<const N: usize> {
+    // Invariant: `buf[..len]` is UTF-8 and `buf[len]` is 0
+    buf: [u8; N],
+    len: usize,
+}
+
+mod try_from {
+    use super::*;
+    use core::convert::TryFrom;
+
+    impl<const N: usize> TryFrom<&str> for CUtf8Array<N> {
+        type Error = Error;
+
+        #[inline]
+        fn try_from(s: &str) -> Result<CUtf8Array<N>, Self::Error> {
+            let mut array = CUtf8Array::new();
+            array.push_str(s)?;
+            Ok(array)
+        }
+    }
+}
+
+impl<const N: usize> Deref for CUtf8Array<N> {
+    type Target = CUtf8;
+
+    #[inline]
+    fn deref(&self) -> &CUtf8 {
+        unsafe { CUtf8::from_bytes_unchecked(self.buf.get_unchecked(..=self.len)) }
+    }
+}
+
+impl<const N: usize> DerefMut for CUtf8Array<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut CUtf8 {
+        unsafe {
+            let bytes = self.buf.get_unchecked_mut(..=self.len);
+            CUtf8::from_str_unchecked_mut(str::from_utf8_unchecked_mut(bytes))
+        }
+    }
+}
+
+impl<const N: usize> Default for CUtf8Array<N> {
+    #[inline]
+    fn default() -> CUtf8Array<N> {
+        CUtf8Array::new()
+    }
+}
+
+impl<const N: usize> PartialEq for CUtf8Array<N> {
+    #[inline]
+    fn eq(&self, other: &CUtf8Array<N>) -> bool {
+        (**self) == **other
+    }
+}
+
+impl<const N: usize> Eq for CUtf8Array<N> {}
+
+impl<const N: usize> PartialOrd for CUtf8Array<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &CUtf8Array<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for CUtf8Array<N> {
+    #[inline]
+    fn cmp(&self, other: &CUtf8Array<N>) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<const N: usize> Hash for CUtf8Array<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<const N: usize> Borrow<CUtf8> for CUtf8Array<N> {
+    #[inline]
+    fn borrow(&self) -> &CUtf8 { self }
+}
+
+impl<const N: usize> Borrow<str> for CUtf8Array<N> {
+    #[inline]
+    fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl<const N: usize> AsRef<CUtf8> for CUtf8Array<N> {
+    #[inline]
+    fn as_ref(&self) -> &CUtf8 { self }
+}
+
+impl<const N: usize> AsRef<str> for CUtf8Array<N> {
+    #[inline]
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl<const N: usize> fmt::Debug for CUtf8Array<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Display for CUtf8Array<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Each write either appends all of its string or fails with `fmt::Error`.
+/// A `write!` with several arguments may still stop partway through.
+impl<const N: usize> fmt::Write for CUtf8Array<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> CUtf8Array<N> {
+    /// Creates a new empty `CUtf8Array`.
+    ///
+    /// This can be used within a constant expression.
+    #[inline]
+    pub const fn new() -> CUtf8Array<N> {
+        const { assert!(N > 0, "CUtf8Array needs room for the nul terminator") };
+        CUtf8Array { buf: [0; N], len: 0 }
+    }
+
+    /// Returns the number of bytes that can be stored, not counting the
+    /// trailing nul byte.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N - 1
+    }
+
+    /// Appends `s` onto the end of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns
+    /// [`Error::CapacityExceeded`](enum.Error.html#variant.CapacityExceeded)
+    /// and leaves `self` unchanged if `s` does not fit.
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        let start = self.len;
+        let end = start + s.len();
+        if end >= N {
+            return Err(Error::CapacityExceeded(self.capacity()));
+        }
+        self.buf[start..end].copy_from_slice(s.as_bytes());
+        self.buf[end] = 0;
+        self.len = end;
+        Ok(())
+    }
+
+    /// Appends the given `char` to the end of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns
+    /// [`Error::CapacityExceeded`](enum.Error.html#variant.CapacityExceeded)
+    /// and leaves `self` unchanged if `c` does not fit.
+    #[inline]
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes the last character and returns it, or `None` if `self` is
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.set_len(self.len - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens `self` to `new_len` bytes, not counting the trailing nul byte.
+    ///
+    /// If `new_len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            assert!(self.as_str().is_char_boundary(new_len),
+                    "new_len does not lie on a char boundary");
+            self.set_len(new_len);
+        }
+    }
+
+    /// Truncates `self` to an empty string.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.set_len(0);
+    }
+
+    #[inline]
+    fn set_len(&mut self, len: usize) {
+        self.buf[len] = 0;
+        self.len = len;
+    }
+
+    /// Returns `self` as a borrowed C string.
+    #[inline]
+    pub fn as_c_utf8(&self) -> &CUtf8 {
+        self
+    }
+
+    /// Returns `self` as a mutable borrowed C string.
+    #[inline]
+    pub fn as_c_utf8_mut(&mut self) -> &mut CUtf8 {
+        self
+    }
+}
@@ -1,9 +1,22 @@
 use std::borrow::{Borrow, BorrowMut, ToOwned};
+use std::ffi::{CStr, CString, OsString};
+use std::cmp::{self, Ordering};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
 use std::iter::FromIterator;
-use std::ops::{Deref, DerefMut};
+use std::mem::ManuallyDrop;
+use std::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::string::Drain;
+use std::sync::Arc;
+use std::{ptr, str};
 
+use c_char;
 use c_utf8::CUtf8;
+use c_utf8_strict_buf::CUtf8StrictBuf;
+use error::{Error, FromOwnedError};
 use ext::Ext;
 
 /// An owned, mutable UTF-8 encoded C string (akin to [`String`] or
@@ -30,23 +43,140 @@ use ext::Ext;
 /// assert_eq!(bytes, b"Hello there, fellow human!\0");
 /// ```
 ///
+/// Like [`CUtf8`](struct.CUtf8.html), it compares and hashes the same as the
+/// string before its trailing nul byte, so maps keyed by it can be queried
+/// with a `&str`:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use c_utf8::CUtf8Buf;
+///
+/// let mut env = HashMap::new();
+/// env.insert(CUtf8Buf::from("HOME"), "/root");
+///
+/// assert_eq!(env.get("HOME"), Some(&"/root"));
+/// ```
+///
+/// A finished string can be shared without copying it for every owner:
+///
+/// ```
+/// use std::sync::Arc;
+/// use c_utf8::{CUtf8, CUtf8Buf};
+///
+/// let config: Arc<CUtf8> = CUtf8Buf::from("verbose=1").into();
+/// let copy = Arc::clone(&config);
+///
+/// assert_eq!(copy.as_bytes_with_nul(), b"verbose=1\0");
+/// ```
+///
 /// [`String`]:  https://doc.rust-lang.org/std/string/struct.String.html
 /// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
 /// [collect]:   https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.collect
-#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone)]
 pub struct CUtf8Buf(String);
 
-impl PartialEq<CUtf8> for CUtf8Buf {
-    #[inline]
-    fn eq(&self, other: &CUtf8) -> bool {
-        (**self) == *other
+mod try_from {
+    use super::*;
+    use std::convert::TryFrom;
+
+    impl TryFrom<CString> for CUtf8Buf {
+        type Error = FromOwnedError<CString>;
+
+        #[inline]
+        fn try_from(c: CString) -> Result<CUtf8Buf, Self::Error> {
+            CUtf8Buf::from_c_string(c)
+        }
+    }
+
+    impl TryFrom<Box<CStr>> for CUtf8Buf {
+        type Error = FromOwnedError<Box<CStr>>;
+
+        #[inline]
+        fn try_from(c: Box<CStr>) -> Result<CUtf8Buf, Self::Error> {
+            CUtf8Buf::from_boxed_c_str(c)
+        }
+    }
+
+    impl TryFrom<CUtf8Buf> for CString {
+        type Error = FromOwnedError<CUtf8Buf>;
+
+        #[inline]
+        fn try_from(buf: CUtf8Buf) -> Result<CString, Self::Error> {
+            buf.into_c_string()
+        }
     }
 }
 
-impl PartialEq<CUtf8Buf> for CUtf8 {
+/// How [`CUtf8Buf::from_string_with`](struct.CUtf8Buf.html#method.from_string_with)
+/// handles nul bytes that appear before the end of the input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NulPolicy {
+    /// Fail with [`Error::InteriorNul`](enum.Error.html#variant.InteriorNul)
+    /// and the position of the first nul byte.
+    Reject,
+    /// Remove every interior nul byte.
+    Strip,
+    /// Replace every interior nul byte with U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+    /// Replace every interior nul byte with the given character.
+    ///
+    /// Replacing with `'\0'` would leave the nul bytes in place, so it fails
+    /// like [`Reject`](#variant.Reject) instead.
+    ReplaceWith(char),
+    /// Discard everything from the first nul byte onward, keeping only what C
+    /// would see.
+    Truncate,
+}
+
+/// The outcome of a single attempt at filling a buffer in
+/// [`CUtf8Buf::from_fill`](struct.CUtf8Buf.html#method.from_fill).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fill {
+    /// The buffer now holds a nul-terminated string, such as after a
+    /// successful call to `getcwd`.
+    Done,
+    /// The string has the given length, not counting a nul byte.
+    ///
+    /// If the length is less than the buffer's capacity, then that many bytes
+    /// were written; a nul terminator is appended if not already there, as
+    /// with `readlink`. Otherwise, the buffer is grown to fit it, as with
+    /// `snprintf`, and at least doubled, since `readlink` only reports that it
+    /// filled the buffer.
+    Written(usize),
+    /// The buffer was too small, such as when `getcwd` fails with `ERANGE`.
+    ///
+    /// If known, the required capacity can be given. Otherwise, the capacity
+    /// is doubled.
+    Grow(Option<usize>),
+}
+
+impl PartialEq for CUtf8Buf {
     #[inline]
     fn eq(&self, other: &CUtf8Buf) -> bool {
-        other.eq(self)
+        (**self) == **other
+    }
+}
+
+impl Eq for CUtf8Buf {}
+
+impl PartialOrd for CUtf8Buf {
+    #[inline]
+    fn partial_cmp(&self, other: &CUtf8Buf) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CUtf8Buf {
+    #[inline]
+    fn cmp(&self, other: &CUtf8Buf) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl Hash for CUtf8Buf {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
     }
 }
 
@@ -80,6 +210,78 @@ impl<T> FromIterator<T> for CUtf8Buf where String: FromIterator<T> {
     }
 }
 
+/// Appends characters before the nul terminator.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::CUtf8Buf;
+///
+/// let mut buf = CUtf8Buf::from("ab");
+/// buf.extend("cd".chars());
+///
+/// assert_eq!(buf.as_bytes_with_nul(), b"abcd\0");
+/// ```
+impl Extend<char> for CUtf8Buf {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.with_string(|inner| inner.extend(iter));
+    }
+}
+
+impl<'a> Extend<&'a char> for CUtf8Buf {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.with_string(|inner| inner.extend(iter));
+    }
+}
+
+/// Appends strings before the nul terminator.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::CUtf8Buf;
+///
+/// let mut buf = CUtf8Buf::from("a");
+/// buf.extend(vec!["b", "c"]);
+///
+/// assert_eq!(buf.as_bytes_with_nul(), b"abc\0");
+/// ```
+impl<'a> Extend<&'a str> for CUtf8Buf {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.with_string(|inner| inner.extend(iter));
+    }
+}
+
+impl Extend<String> for CUtf8Buf {
+    #[inline]
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.with_string(|inner| inner.extend(iter));
+    }
+}
+
+/// Appends C strings without their nul terminators, keeping only the one at
+/// the end.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8Buf; fn main() {
+/// let mut buf = CUtf8Buf::new();
+/// buf.extend(vec![c_utf8!("a"), c_utf8!("b")]);
+///
+/// assert_eq!(buf.as_bytes_with_nul(), b"ab\0");
+/// # }
+/// ```
+impl<'a> Extend<&'a CUtf8> for CUtf8Buf {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a CUtf8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(CUtf8::as_str));
+    }
+}
+
 impl fmt::Debug for CUtf8Buf {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -118,6 +320,11 @@ impl Borrow<CUtf8> for CUtf8Buf {
     fn borrow(&self) -> &CUtf8 { self }
 }
 
+impl Borrow<str> for CUtf8Buf {
+    #[inline]
+    fn borrow(&self) -> &str { self.as_str() }
+}
+
 impl BorrowMut<CUtf8> for CUtf8Buf {
     #[inline]
     fn borrow_mut(&mut self) -> &mut CUtf8 { self }
@@ -133,6 +340,16 @@ impl AsMut<CUtf8> for CUtf8Buf {
     fn as_mut(&mut self) -> &mut CUtf8 { self }
 }
 
+impl AsRef<str> for CUtf8Buf {
+    #[inline]
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl AsRef<[u8]> for CUtf8Buf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
 impl ToOwned for CUtf8 {
     type Owned = CUtf8Buf;
 
@@ -142,14 +359,14 @@ impl ToOwned for CUtf8 {
     }
 }
 
-impl<'a> From<&'a CUtf8> for CUtf8Buf {
+impl From<&CUtf8> for CUtf8Buf {
     #[inline]
     fn from(c: &CUtf8) -> CUtf8Buf {
         c.to_owned()
     }
 }
 
-impl<'a> From<&'a mut CUtf8> for CUtf8Buf {
+impl From<&mut CUtf8> for CUtf8Buf {
     #[inline]
     fn from(c: &mut CUtf8) -> CUtf8Buf {
         c.to_owned()
@@ -163,14 +380,14 @@ impl From<String> for CUtf8Buf {
     }
 }
 
-impl<'a> From<&'a str> for CUtf8Buf {
+impl From<&str> for CUtf8Buf {
     #[inline]
     fn from(s: &str) -> CUtf8Buf {
         String::from(s).into()
     }
 }
 
-impl<'a> From<&'a mut str> for CUtf8Buf {
+impl From<&mut str> for CUtf8Buf {
     #[inline]
     fn from(c: &mut str) -> CUtf8Buf {
         (c as &str).into()
@@ -193,6 +410,74 @@ impl From<CUtf8Buf> for Box<CUtf8> {
     }
 }
 
+impl From<&CUtf8> for Box<CUtf8> {
+    #[inline]
+    fn from(c: &CUtf8) -> Box<CUtf8> {
+        let raw = Box::into_raw(Box::<str>::from(c.as_str_with_nul()));
+        unsafe { Box::from_raw(raw as *mut CUtf8) }
+    }
+}
+
+impl From<&str> for Box<CUtf8> {
+    #[inline]
+    fn from(s: &str) -> Box<CUtf8> {
+        CUtf8Buf::from(s).into()
+    }
+}
+
+impl From<String> for Box<CUtf8> {
+    #[inline]
+    fn from(s: String) -> Box<CUtf8> {
+        CUtf8Buf::from(s).into()
+    }
+}
+
+impl Clone for Box<CUtf8> {
+    #[inline]
+    fn clone(&self) -> Box<CUtf8> {
+        (**self).into()
+    }
+}
+
+impl Default for Box<CUtf8> {
+    #[inline]
+    fn default() -> Box<CUtf8> {
+        CUtf8::EMPTY.into()
+    }
+}
+
+impl From<&CUtf8> for Rc<CUtf8> {
+    #[inline]
+    fn from(c: &CUtf8) -> Rc<CUtf8> {
+        let raw = Rc::into_raw(Rc::<str>::from(c.as_str_with_nul()));
+        unsafe { Rc::from_raw(raw as *const CUtf8) }
+    }
+}
+
+impl From<CUtf8Buf> for Rc<CUtf8> {
+    #[inline]
+    fn from(buf: CUtf8Buf) -> Rc<CUtf8> {
+        let raw = Rc::into_raw(Rc::<str>::from(buf.0));
+        unsafe { Rc::from_raw(raw as *const CUtf8) }
+    }
+}
+
+impl From<&CUtf8> for Arc<CUtf8> {
+    #[inline]
+    fn from(c: &CUtf8) -> Arc<CUtf8> {
+        let raw = Arc::into_raw(Arc::<str>::from(c.as_str_with_nul()));
+        unsafe { Arc::from_raw(raw as *const CUtf8) }
+    }
+}
+
+impl From<CUtf8Buf> for Arc<CUtf8> {
+    #[inline]
+    fn from(buf: CUtf8Buf) -> Arc<CUtf8> {
+        let raw = Arc::into_raw(Arc::<str>::from(buf.0));
+        unsafe { Arc::from_raw(raw as *const CUtf8) }
+    }
+}
+
 impl From<CUtf8Buf> for String {
     #[inline]
     fn from(buf: CUtf8Buf) -> String {
@@ -207,6 +492,58 @@ impl From<CUtf8Buf> for Vec<u8> {
     }
 }
 
+// Tracks pointers returned by `into_raw` so that `from_raw` can catch foreign
+// pointers in debug builds
+#[cfg(debug_assertions)]
+mod raw_tracking {
+    use std::collections::BTreeSet;
+    use std::sync::{Mutex, PoisonError};
+
+    use c_char;
+
+    static LIVE: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+    pub fn track(ptr: *mut c_char) {
+        LIVE.lock().unwrap_or_else(PoisonError::into_inner).insert(ptr as usize);
+    }
+
+    pub fn untrack(ptr: *mut c_char) -> bool {
+        LIVE.lock().unwrap_or_else(PoisonError::into_inner).remove(&(ptr as usize))
+    }
+}
+
+impl CUtf8 {
+    /// Transfers ownership of the boxed string to a C caller.
+    ///
+    /// This behaves the same as
+    /// [`CUtf8Buf::into_raw`](struct.CUtf8Buf.html#method.into_raw).
+    #[inline]
+    pub fn into_raw(self: Box<CUtf8>) -> *mut c_char {
+        CUtf8Buf::from(self).into_raw()
+    }
+
+    /// Retakes ownership of a string that was transferred to C via
+    /// [`into_raw`](#method.into_raw).
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for
+    /// [`CUtf8Buf::from_raw`](struct.CUtf8Buf.html#method.from_raw) apply.
+    #[inline]
+    pub unsafe fn from_raw(ptr: *mut c_char) -> Box<CUtf8> {
+        CUtf8Buf::from_raw(ptr).into()
+    }
+
+    /// Consumes and leaks the boxed string, returning a reference that lives
+    /// for the rest of the program.
+    ///
+    /// This is useful for C APIs that hold onto a string forever.
+    #[inline]
+    pub fn leak<'a>(self: Box<CUtf8>) -> &'a mut CUtf8 {
+        Box::leak(self)
+    }
+}
+
 impl CUtf8Buf {
     /// Creates a new empty `CUtf8Buf`.
     #[inline]
@@ -214,6 +551,44 @@ impl CUtf8Buf {
         CUtf8Buf(unsafe { String::from_utf8_unchecked(vec![0; 1]) })
     }
 
+    /// Creates a new empty `CUtf8Buf` with room for at least `capacity` bytes,
+    /// not counting the nul terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::with_capacity(5);
+    /// let cap = buf.capacity();
+    /// assert!(cap >= 5);
+    ///
+    /// buf.push_str("hello");
+    /// assert_eq!(buf.capacity(), cap);
+    /// ```
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> CUtf8Buf {
+        let mut bytes = Vec::with_capacity(capacity.saturating_add(1));
+        bytes.push(0);
+        CUtf8Buf(unsafe { String::from_utf8_unchecked(bytes) })
+    }
+
+    /// Creates a new C string from the raw components of another.
+    ///
+    /// Both `length` and `capacity` include the nul terminator, as returned by
+    /// [`into_raw_parts`](#method.into_raw_parts).
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for
+    /// [`String::from_raw_parts`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_raw_parts)
+    /// apply. In addition, the first `length` bytes must be valid UTF-8 and
+    /// end with a nul byte.
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: *mut c_char, length: usize, capacity: usize) -> CUtf8Buf {
+        CUtf8Buf(String::from_raw_parts(ptr as *mut u8, length, capacity))
+    }
+
     /// Creates a new C string from a UTF-8 string, appending a nul
     /// terminator if one doesn't already exist.
     #[inline]
@@ -224,8 +599,277 @@ impl CUtf8Buf {
         CUtf8Buf(s)
     }
 
+    /// Creates a new C string from bytes, appending a nul terminator if one
+    /// doesn't already exist, or hands back `bytes` if they are not encoded as
+    /// UTF-8.
+    ///
+    /// On success, the allocation of `bytes` is reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let buf = CUtf8Buf::from_vec(b"hello".to_vec()).unwrap();
+    /// assert_eq!(buf.as_bytes_with_nul(), b"hello\0");
+    /// ```
+    #[inline]
+    pub fn from_vec(bytes: Vec<u8>) -> Result<CUtf8Buf, FromOwnedError<Vec<u8>>> {
+        match String::from_utf8(bytes) {
+            Ok(s) => Ok(CUtf8Buf::from_string(s)),
+            Err(err) => {
+                let error = err.utf8_error().into();
+                Err(FromOwnedError::new(err.into_bytes(), error))
+            },
+        }
+    }
+
+    /// Creates a new C string from an owned C string, or hands back `c` if it
+    /// is not encoded as UTF-8.
+    ///
+    /// On success, the allocation of `c` is reused.
+    #[inline]
+    pub fn from_c_string(c: CString) -> Result<CUtf8Buf, FromOwnedError<CString>> {
+        match c.into_string() {
+            Ok(s) => Ok(CUtf8Buf::from_string(s)),
+            Err(err) => {
+                let error = err.utf8_error().into();
+                Err(FromOwnedError::new(err.into_cstring(), error))
+            },
+        }
+    }
+
+    /// Creates a new C string from a boxed C string, or hands back `c` if it
+    /// is not encoded as UTF-8.
+    ///
+    /// On success, the allocation of `c` is reused.
+    #[inline]
+    pub fn from_boxed_c_str(c: Box<CStr>) -> Result<CUtf8Buf, FromOwnedError<Box<CStr>>> {
+        CUtf8Buf::from_c_string(c.into())
+            .map_err(|err| err.map(CString::into_boxed_c_str))
+    }
+
+    /// Creates a new C string from an owned platform string, or hands back
+    /// `os` if it is not valid Unicode.
+    ///
+    /// On success, the allocation of `os` is reused.
+    #[inline]
+    pub fn from_os_string(os: OsString) -> Result<CUtf8Buf, FromOwnedError<OsString>> {
+        match os.into_string() {
+            Ok(s) => Ok(CUtf8Buf::from_string(s)),
+            Err(os) => {
+                let error = match str::from_utf8(os.as_encoded_bytes()) {
+                    Err(err) => err.into(),
+                    Ok(_) => unreachable!("OsString failed to convert to String"),
+                };
+                Err(FromOwnedError::new(os, error))
+            },
+        }
+    }
+
+    /// Creates a new C string from an owned path, or hands back `path` if it
+    /// is not valid Unicode.
+    ///
+    /// On success, the allocation of `path` is reused.
+    #[inline]
+    pub fn from_path_buf(path: PathBuf) -> Result<CUtf8Buf, FromOwnedError<PathBuf>> {
+        CUtf8Buf::from_os_string(path.into_os_string())
+            .map_err(|err| err.map(PathBuf::from))
+    }
+
+    /// Creates a new C string from a UTF-8 string, handling any nul bytes
+    /// before its end according to `policy`.
+    ///
+    /// A single trailing nul byte in `s` is treated as the terminator and is
+    /// not subject to `policy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InteriorNul`](enum.Error.html#variant.InteriorNul)
+    /// with the position of the first nul byte if `s` has one before its end
+    /// and `policy` is `Reject` or `ReplaceWith('\0')`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::{CUtf8Buf, Error, NulPolicy};
+    ///
+    /// let s = || String::from("a\0b\0c");
+    ///
+    /// let strip = CUtf8Buf::from_string_with(s(), NulPolicy::Strip).unwrap();
+    /// assert_eq!(strip.as_str(), "abc");
+    ///
+    /// let with = CUtf8Buf::from_string_with(s(), NulPolicy::ReplaceWith('_'));
+    /// assert_eq!(with.unwrap().as_str(), "a_b_c");
+    ///
+    /// let trunc = CUtf8Buf::from_string_with(s(), NulPolicy::Truncate).unwrap();
+    /// assert_eq!(trunc.as_str(), "a");
+    ///
+    /// match CUtf8Buf::from_string_with(s(), NulPolicy::Reject) {
+    ///     Err(Error::InteriorNul(1)) => {},
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// match CUtf8Buf::from_string_with(s(), NulPolicy::ReplaceWith('\0')) {
+    ///     Err(Error::InteriorNul(1)) => {},
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn from_string_with(mut s: String, policy: NulPolicy) -> Result<CUtf8Buf, Error> {
+        if s.is_nul_terminated() {
+            unsafe { s.as_mut_vec().pop() };
+        }
+        if let Some(pos) = s.first_nul() {
+            match policy {
+                NulPolicy::Reject | NulPolicy::ReplaceWith('\0') => {
+                    return Err(Error::InteriorNul(pos));
+                },
+                NulPolicy::Strip => s.retain(|c| c != '\0'),
+                NulPolicy::Replace => s = s.replace('\0', "\u{FFFD}"),
+                NulPolicy::ReplaceWith(c) => {
+                    s = s.replace('\0', c.encode_utf8(&mut [0; 4]));
+                },
+                NulPolicy::Truncate => s.truncate(pos),
+            }
+        }
+        unsafe { s.as_mut_vec().push(0) };
+        Ok(CUtf8Buf(s))
+    }
+
+    /// Creates a new C string by having `fill` write into a buffer, which is
+    /// grown until the string fits.
+    ///
+    /// This is for the common C pattern of passing a buffer and its capacity,
+    /// then retrying with a larger buffer if it was too small. `fill` receives
+    /// a pointer to the buffer and its capacity in bytes, and reports what
+    /// happened via [`Fill`](enum.Fill.html). The buffer is zeroed before each
+    /// call. The result is written directly into the storage of the returned
+    /// `CUtf8Buf` without an extra copy.
+    ///
+    /// # Errors
+    ///
+    /// Errors returned by `fill` are passed through. If the result is missing
+    /// its nul terminator or is not valid UTF-8, the
+    /// [`Error`](enum.Error.html) is returned as an
+    /// [`io::Error`](https://doc.rust-lang.org/std/io/struct.Error.html) of
+    /// kind `InvalidData`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::os::raw::{c_char, c_int};
+    /// use c_utf8::{CUtf8Buf, Fill};
+    ///
+    /// extern "C" {
+    ///     fn snprintf(s: *mut c_char, n: usize, format: *const c_char, ...) -> c_int;
+    /// }
+    ///
+    /// let long = "x".repeat(1000);
+    /// let long = CUtf8Buf::from(long);
+    ///
+    /// let buf = CUtf8Buf::from_fill(|ptr, cap| unsafe {
+    ///     let format = b"%s!\0".as_ptr() as *const c_char;
+    ///     match snprintf(ptr, cap, format, long.as_ptr()) {
+    ///         n if n < 0 => Err(std::io::Error::last_os_error()),
+    ///         n => Ok(Fill::Written(n as usize)),
+    ///     }
+    /// }).unwrap();
+    ///
+    /// assert_eq!(buf.len(), 1001);
+    /// assert!(buf.as_str().ends_with("x!"));
+    /// ```
+    ///
+    /// APIs like `gethostname` may not terminate a string that they cut
+    /// short. Since each attempt starts with a zeroed buffer, a string from a
+    /// larger buffer is always terminated:
+    ///
+    /// ```
+    /// use std::{cmp, ptr, slice};
+    /// use c_utf8::{CUtf8Buf, Fill};
+    ///
+    /// let host = "build-server.example.com".repeat(6);
+    ///
+    /// let buf = CUtf8Buf::from_fill(|ptr, cap| unsafe {
+    ///     let bytes = slice::from_raw_parts_mut(ptr as *mut u8, cap);
+    ///     assert!(bytes.iter().all(|&b| b == 0));
+    ///
+    ///     let n = cmp::min(host.len(), cap);
+    ///     ptr::copy_nonoverlapping(host.as_ptr(), bytes.as_mut_ptr(), n);
+    ///
+    ///     if n == cap {
+    ///         Ok(Fill::Grow(None))
+    ///     } else {
+    ///         Ok(Fill::Done)
+    ///     }
+    /// }).unwrap();
+    ///
+    /// assert_eq!(buf.as_str(), host);
+    /// ```
+    ///
+    /// `readlink` returns the capacity when it cuts a string short, without
+    /// saying how long the string is. The buffer then doubles on each attempt:
+    ///
+    /// ```
+    /// use std::{cmp, ptr};
+    /// use c_utf8::{CUtf8Buf, Fill};
+    ///
+    /// let target = "/very/long/path".repeat(300);
+    /// let mut calls = 0;
+    ///
+    /// let buf = CUtf8Buf::from_fill(|ptr, cap| unsafe {
+    ///     calls += 1;
+    ///     let n = cmp::min(target.len(), cap);
+    ///     ptr::copy_nonoverlapping(target.as_ptr(), ptr as *mut u8, n);
+    ///     Ok(Fill::Written(n))
+    /// }).unwrap();
+    ///
+    /// assert_eq!(buf.as_str(), target);
+    /// assert_eq!(calls, 7);
+    /// ```
+    pub fn from_fill<F>(mut fill: F) -> io::Result<CUtf8Buf>
+        where F: FnMut(*mut c_char, usize) -> io::Result<Fill>
+    {
+        const INITIAL_CAPACITY: usize = 128;
+
+        let mut bytes = Vec::<u8>::with_capacity(INITIAL_CAPACITY);
+        loop {
+            // Zero everything so that nothing from a previous attempt remains
+            bytes.clear();
+            bytes.resize(bytes.capacity(), 0);
+            let cap = bytes.len();
+
+            let needed = match fill(bytes.as_mut_ptr() as *mut c_char, cap)? {
+                Fill::Done => match bytes.first_nul() {
+                    Some(pos) => {
+                        bytes.truncate(pos + 1);
+                        break;
+                    },
+                    None => return Err(Error::MissingNul.into()),
+                },
+                Fill::Written(len) if len < cap => {
+                    bytes.truncate(len);
+                    bytes.push(0);
+                    break;
+                },
+                Fill::Written(len) => cmp::max(len + 1, cap * 2),
+                Fill::Grow(Some(needed)) if needed > cap => needed,
+                Fill::Grow(_) => cap * 2,
+            };
+            bytes.reserve_exact(needed - cap);
+        }
+
+        match String::from_utf8(bytes) {
+            Ok(s) => Ok(CUtf8Buf(s)),
+            Err(err) => Err(Error::from(err.utf8_error()).into()),
+        }
+    }
+
     /// Creates a new C string from a native Rust string without checking for a
     /// nul terminator.
+    ///
+    /// # Safety
+    ///
+    /// The string must end with a nul byte.
     #[inline]
     pub unsafe fn from_string_unchecked(s: String) -> CUtf8Buf {
         CUtf8Buf(s)
@@ -235,15 +879,38 @@ impl CUtf8Buf {
     fn with_string<F, T>(&mut self, f: F) -> T
         where F: FnOnce(&mut String) -> T
     {
+        // Appends the nul byte back even if `f` panics
+        struct Guard<'a>(&'a mut String);
+
+        impl Drop for Guard<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                unsafe { self.0.as_mut_vec().push(0) };
+            }
+        }
+
         // Remove nul byte
         unsafe { self.0.as_mut_vec().pop() };
 
-        let val = f(&mut self.0);
-
-        // Append nul byte
-        unsafe { self.0.as_mut_vec().push(0) };
+        let guard = Guard(&mut self.0);
+        f(guard.0)
+    }
 
-        val
+    // Resolves `range` against the bytes before the nul terminator
+    fn content_range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("range end overflow"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(end <= len, "range end {} is out of bounds for length {}", end, len);
+        start..end
     }
 
     /// Appends a given string slice onto the end of this `CUtf8Buf`.
@@ -258,6 +925,314 @@ impl CUtf8Buf {
         self.with_string(|inner| inner.push(c));
     }
 
+    /// Inserts a character into this `CUtf8Buf` at a byte position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than [`len`](struct.CUtf8.html#method.len) or
+    /// if it does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("ac");
+    /// buf.insert(1, 'b');
+    /// buf.insert(3, 'd');
+    ///
+    /// assert_eq!(buf.as_bytes_with_nul(), b"abcd\0");
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.with_string(|inner| inner.insert(idx, c));
+    }
+
+    /// Inserts a string slice into this `CUtf8Buf` at a byte position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than [`len`](struct.CUtf8.html#method.len) or
+    /// if it does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("bar");
+    /// buf.insert_str(0, "foo");
+    /// buf.insert_str(6, "baz");
+    ///
+    /// assert_eq!(buf.as_bytes_with_nul(), b"foobarbaz\0");
+    /// ```
+    #[inline]
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.with_string(|inner| inner.insert_str(idx, s));
+    }
+
+    /// Removes a `char` from this `CUtf8Buf` at a byte position and returns
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not less than [`len`](struct.CUtf8.html#method.len)
+    /// or if it does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("abc");
+    ///
+    /// assert_eq!(buf.remove(2), 'c');
+    /// assert_eq!(buf.as_bytes_with_nul(), b"ab\0");
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        self.with_string(|inner| inner.remove(idx))
+    }
+
+    /// Removes the last character before the nul terminator and returns it,
+    /// or `None` if `self` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("ab");
+    ///
+    /// assert_eq!(buf.pop(), Some('b'));
+    /// assert_eq!(buf.as_bytes_with_nul(), b"a\0");
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.with_string(|inner| inner.pop())
+    }
+
+    /// Shortens this `CUtf8Buf` to the specified length, not counting the nul
+    /// terminator.
+    ///
+    /// If `new_len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("hello");
+    /// buf.truncate(2);
+    ///
+    /// assert_eq!(buf.as_bytes_with_nul(), b"he\0");
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.with_string(|inner| inner.truncate(new_len));
+    }
+
+    /// Removes everything but the nul terminator, keeping the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("hello");
+    /// buf.clear();
+    ///
+    /// assert!(buf.is_empty());
+    /// assert_eq!(buf.as_bytes_with_nul(), b"\0");
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the characters specified by the predicate.
+    ///
+    /// The nul terminator is always kept and is not passed to `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("a1b2c3");
+    /// buf.retain(|c| c.is_alphabetic());
+    ///
+    /// assert_eq!(buf.as_bytes_with_nul(), b"abc\0");
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, f: F) {
+        self.with_string(|inner| inner.retain(f));
+    }
+
+    /// Removes the specified range from this `CUtf8Buf` in bulk, returning all
+    /// removed characters as an iterator.
+    ///
+    /// The range is relative to the bytes before the nul terminator, which is
+    /// never removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of the range does not lie on a `char`
+    /// boundary, or if the range extends past the nul terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("hello, world");
+    /// let hello: String = buf.drain(..7).collect();
+    ///
+    /// assert_eq!(hello, "hello, ");
+    /// assert_eq!(buf.as_bytes_with_nul(), b"world\0");
+    /// ```
+    ///
+    /// The nul terminator cannot be drained:
+    ///
+    /// ```
+    /// use std::panic::{self, AssertUnwindSafe};
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("abc");
+    /// let len = buf.len();
+    ///
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     buf.drain(..len + 1);
+    /// }));
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(buf.as_bytes_with_nul(), b"abc\0");
+    /// ```
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_> {
+        let range = self.content_range(range);
+        self.0.drain(range)
+    }
+
+    /// Removes the specified range and replaces it with the given string.
+    ///
+    /// The range is relative to the bytes before the nul terminator, which is
+    /// never replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of the range does not lie on a `char`
+    /// boundary, or if the range extends past the nul terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut buf = CUtf8Buf::from("key=old");
+    /// buf.replace_range(4.., "new value");
+    ///
+    /// assert_eq!(buf.as_bytes_with_nul(), b"key=new value\0");
+    /// ```
+    #[inline]
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, replace_with: &str) {
+        let range = self.content_range(range);
+        self.0.replace_range(range, replace_with);
+    }
+
+    /// Splits this `CUtf8Buf` into two at the given byte index, returning
+    /// everything after it as a new nul-terminated `CUtf8Buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is larger than [`len`](struct.CUtf8.html#method.len) or
+    /// if it does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let mut head = CUtf8Buf::from("key=value");
+    /// let tail = head.split_off(4);
+    ///
+    /// assert_eq!(head.as_bytes_with_nul(), b"key=\0");
+    /// assert_eq!(tail.as_bytes_with_nul(), b"value\0");
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> CUtf8Buf {
+        let mut tail = self.with_string(|inner| inner.split_off(at));
+        unsafe { tail.as_mut_vec().push(0) };
+        CUtf8Buf(tail)
+    }
+
+    /// Returns the number of bytes that can be held without reallocating, not
+    /// counting the nul terminator.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity_with_nul() - 1
+    }
+
+    /// Returns the number of bytes that can be held without reallocating,
+    /// including the nul terminator.
+    #[inline]
+    pub fn capacity_with_nul(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be appended.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more bytes to be appended.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.0.reserve_exact(additional);
+    }
+
+    /// Shrinks the capacity to match the length, keeping room for the nul
+    /// terminator.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity to at least `min_capacity` bytes, not counting the
+    /// nul terminator.
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.0.shrink_to(min_capacity.saturating_add(1));
+    }
+
+    /// Decomposes `self` into its raw components: a pointer to the start of
+    /// the string, its length, and its capacity.
+    ///
+    /// Both the length and capacity include the nul terminator. The parts can
+    /// be put back together with [`from_raw_parts`](#method.from_raw_parts).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let (ptr, len, cap) = CUtf8Buf::from("abc").into_raw_parts();
+    /// assert_eq!(len, 4);
+    ///
+    /// let buf = unsafe { CUtf8Buf::from_raw_parts(ptr, len, cap) };
+    /// assert_eq!(buf.as_str(), "abc");
+    /// ```
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut c_char, usize, usize) {
+        let mut string = ManuallyDrop::new(self.0);
+        (string.as_mut_ptr() as *mut c_char, string.len(), string.capacity())
+    }
+
     /// Converts `self` into a native UTF-8 encoded Rust
     /// [`String`](https://doc.rust-lang.org/std/string/struct.String.html).
     #[inline]
@@ -288,4 +1263,114 @@ impl CUtf8Buf {
     pub fn into_bytes_with_nul(self) -> Vec<u8> {
         self.into_string_with_nul().into()
     }
+
+    /// Converts `self` into a
+    /// [`CString`](https://doc.rust-lang.org/std/ffi/struct.CString.html), or
+    /// hands back `self` if it has an interior nul byte.
+    ///
+    /// This does not copy unless the buffer has excess capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let c = CUtf8Buf::from("hello").into_c_string().unwrap();
+    /// assert_eq!(c.as_bytes_with_nul(), b"hello\0");
+    ///
+    /// let bad = CUtf8Buf::from("a\0b");
+    /// assert_eq!(bad.into_c_string().unwrap_err().error().position(), Some(1));
+    /// ```
+    #[inline]
+    pub fn into_c_string(self) -> Result<CString, FromOwnedError<CUtf8Buf>> {
+        CUtf8StrictBuf::from_c_utf8_buf(self).map(CString::from)
+    }
+
+    /// Converts `self` into a boxed
+    /// [`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html), or hands
+    /// back `self` if it has an interior nul byte.
+    ///
+    /// This does not copy unless the buffer has excess capacity.
+    #[inline]
+    pub fn into_boxed_c_str(self) -> Result<Box<CStr>, FromOwnedError<CUtf8Buf>> {
+        self.into_c_string().map(CString::into_boxed_c_str)
+    }
+
+    /// Transfers ownership of the string to a C caller, akin to
+    /// [`CString::into_raw`](https://doc.rust-lang.org/std/ffi/struct.CString.html#method.into_raw).
+    ///
+    /// The pointer must be returned to Rust via
+    /// [`from_raw`](#method.from_raw) to be properly deallocated. It must
+    /// **not** be freed by C's `free`.
+    ///
+    /// Since `from_raw` finds the end of the string with `strlen`, anything
+    /// after the first nul byte is discarded. C cannot see those bytes anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8Buf;
+    ///
+    /// let ptr = CUtf8Buf::from("hello").into_raw();
+    ///
+    /// // ... pass `ptr` to C and get it back ...
+    ///
+    /// let buf = unsafe { CUtf8Buf::from_raw(ptr) };
+    /// assert_eq!(buf.as_str(), "hello");
+    /// ```
+    pub fn into_raw(self) -> *mut c_char {
+        let mut string = self.0;
+        if let Some(pos) = string.first_nul() {
+            string.truncate(pos + 1);
+        }
+        let ptr = Box::into_raw(string.into_boxed_str()) as *mut c_char;
+
+        #[cfg(debug_assertions)]
+        raw_tracking::track(ptr);
+
+        ptr
+    }
+
+    /// Retakes ownership of a string that was transferred to C via
+    /// [`into_raw`](#method.into_raw).
+    ///
+    /// In debug builds, this panics if `ptr` was not returned by `into_raw` or
+    /// was already retaken.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by `CUtf8Buf::into_raw` or
+    /// `CUtf8::into_raw` and must not have been retaken since. C may have
+    /// modified the string in place, but it must still be valid UTF-8 and its
+    /// first nul byte must not have moved.
+    pub unsafe fn from_raw(ptr: *mut c_char) -> CUtf8Buf {
+        #[cfg(debug_assertions)] {
+            if !raw_tracking::untrack(ptr) {
+                panic!("pointer passed to from_raw was not returned by into_raw");
+            }
+        }
+
+        let len = CStr::from_ptr(ptr).to_bytes_with_nul().len();
+        let raw = ptr::slice_from_raw_parts_mut(ptr as *mut u8, len) as *mut str;
+        CUtf8Buf(Box::from_raw(raw).into())
+    }
+
+    /// Consumes and leaks the string, returning a reference that lives for the
+    /// rest of the program.
+    ///
+    /// This is useful for C APIs that hold onto a string forever, such as
+    /// `setlocale` or `openlog`. Excess capacity is freed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::{CUtf8, CUtf8Buf};
+    ///
+    /// let ident: &'static CUtf8 = CUtf8Buf::from("my-daemon").leak();
+    /// assert_eq!(ident.as_str(), "my-daemon");
+    /// ```
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut CUtf8 {
+        Box::<CUtf8>::from(self).leak()
+    }
 }
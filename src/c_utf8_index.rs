@@ -0,0 +1,116 @@
+use core::ops::{
+    Index,
+    Range,
+    RangeFrom,
+    RangeFull,
+    RangeInclusive,
+    RangeTo,
+    RangeToInclusive,
+};
+
+use c_utf8::CUtf8;
+
+/// A range that can index into a [`CUtf8`](struct.CUtf8.html) via
+/// [`get`](struct.CUtf8.html#method.get) or `[]`.
+///
+/// Every suffix of a C string is itself nul-terminated. So ranges that are
+/// open at the end (`n..` and `..`) produce a `CUtf8`, while all others
+/// produce a `str`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8; fn main() {
+/// let s = c_utf8!("hello");
+///
+/// let tail: &CUtf8 = &s[2..];
+/// let head: &str = &s[..2];
+///
+/// assert_eq!(tail.as_bytes_with_nul(), b"llo\0");
+/// assert_eq!(head, "he");
+/// # }
+/// ```
+pub trait CUtf8Index: sealed::Sealed {
+    /// The type returned by indexing.
+    type Output: ?Sized;
+
+    #[doc(hidden)]
+    fn __get(self, c: &CUtf8) -> Option<&Self::Output>;
+
+    #[doc(hidden)]
+    fn __index(self, c: &CUtf8) -> &Self::Output;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl<I: CUtf8Index> Index<I> for CUtf8 {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        index.__index(self)
+    }
+}
+
+impl sealed::Sealed for RangeFrom<usize> {}
+
+impl CUtf8Index for RangeFrom<usize> {
+    type Output = CUtf8;
+
+    #[inline]
+    fn __get(self, c: &CUtf8) -> Option<&CUtf8> {
+        c.as_str().get(self).map(|rest| c.suffix(rest))
+    }
+
+    #[inline]
+    fn __index(self, c: &CUtf8) -> &CUtf8 {
+        c.suffix(&c.as_str()[self])
+    }
+}
+
+impl sealed::Sealed for RangeFull {}
+
+impl CUtf8Index for RangeFull {
+    type Output = CUtf8;
+
+    #[inline]
+    fn __get(self, c: &CUtf8) -> Option<&CUtf8> {
+        Some(c)
+    }
+
+    #[inline]
+    fn __index(self, c: &CUtf8) -> &CUtf8 {
+        c
+    }
+}
+
+macro_rules! impl_str_index {
+    ($($t:ty),+) => { $(
+        impl sealed::Sealed for $t {}
+
+        impl CUtf8Index for $t {
+            type Output = str;
+
+            #[inline]
+            fn __get(self, c: &CUtf8) -> Option<&str> {
+                c.as_str().get(self)
+            }
+
+            #[inline]
+            fn __index(self, c: &CUtf8) -> &str {
+                &c.as_str()[self]
+            }
+        }
+    )+ }
+}
+
+impl_str_index! {
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>
+}
@@ -0,0 +1,198 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use core::str::Utf8Error;
+
+use c_char;
+use c_utf8::CUtf8;
+use c_utf8_strict::CUtf8Strict;
+
+/// A thin, non-null pointer to a [`CUtf8`](struct.CUtf8.html) that is
+/// ABI-compatible with C's `const char *`.
+///
+/// Unlike `&CUtf8`, which is a fat pointer carrying its length, this type is a
+/// single pointer. This allows for using it in `extern "C"` function
+/// signatures and `#[repr(C)]` structs without giving up the lifetime or the
+/// UTF-8 guarantee. The length is computed with `strlen` only when needed.
+///
+/// Use `Option<CUtf8Ref>` for a nullable `const char *`; it has the same
+/// layout.
+///
+/// Because C stops reading at the first nul byte, a `CUtf8Ref` created from a
+/// `CUtf8` with interior nul bytes only refers to the part before the first
+/// one.
+///
+/// # Examples
+///
+/// A `CUtf8Ref` can be created from the [`c_utf8!`](macro.c_utf8.html) macro
+/// within a constant expression:
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// use c_utf8::{c_char, CUtf8Ref};
+///
+/// #[repr(C)]
+/// struct Options {
+///     name: CUtf8Ref<'static>,
+///     comment: Option<CUtf8Ref<'static>>,
+/// }
+///
+/// const OPTIONS: Options = Options {
+///     name: CUtf8Ref::new(c_utf8!("app")),
+///     comment: None,
+/// };
+///
+/// assert_eq!(std::mem::size_of::<Options>(), 2 * std::mem::size_of::<*const c_char>());
+/// assert_eq!(OPTIONS.name.as_c_utf8().as_str(), "app");
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct CUtf8Ref<'a> {
+    ptr: NonNull<c_char>,
+    marker: PhantomData<&'a CUtf8>,
+}
+
+// Safe because `CUtf8Ref` behaves like `&CUtf8`
+unsafe impl<'a> Send for CUtf8Ref<'a> {}
+unsafe impl<'a> Sync for CUtf8Ref<'a> {}
+
+impl<'a> From<&'a CUtf8> for CUtf8Ref<'a> {
+    #[inline]
+    fn from(c: &'a CUtf8) -> CUtf8Ref<'a> {
+        CUtf8Ref::new(c)
+    }
+}
+
+impl<'a> From<&'a CUtf8Strict> for CUtf8Ref<'a> {
+    #[inline]
+    fn from(c: &'a CUtf8Strict) -> CUtf8Ref<'a> {
+        CUtf8Ref::new(c.as_c_utf8())
+    }
+}
+
+impl<'a> From<CUtf8Ref<'a>> for &'a CUtf8 {
+    #[inline]
+    fn from(c: CUtf8Ref<'a>) -> &'a CUtf8 {
+        c.as_c_utf8()
+    }
+}
+
+impl<'a> Default for CUtf8Ref<'a> {
+    #[inline]
+    fn default() -> CUtf8Ref<'a> {
+        CUtf8Ref::new(CUtf8::EMPTY)
+    }
+}
+
+impl<'a> PartialEq for CUtf8Ref<'a> {
+    #[inline]
+    fn eq(&self, other: &CUtf8Ref<'a>) -> bool {
+        self.as_c_utf8() == other.as_c_utf8()
+    }
+}
+
+impl<'a> Eq for CUtf8Ref<'a> {}
+
+impl<'a> fmt::Debug for CUtf8Ref<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_c_utf8().fmt(f)
+    }
+}
+
+impl<'a> fmt::Display for CUtf8Ref<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_c_utf8().fmt(f)
+    }
+}
+
+impl<'a> CUtf8Ref<'a> {
+    /// Creates a thin pointer to the start of `c`.
+    ///
+    /// This can be used within a constant expression.
+    #[inline]
+    pub const fn new(c: &'a CUtf8) -> CUtf8Ref<'a> {
+        CUtf8Ref {
+            ptr: unsafe { NonNull::new_unchecked(c.as_ptr() as *mut c_char) },
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a thin pointer to the raw C string if it is non-null and valid
+    /// UTF-8 up to the first nul byte.
+    ///
+    /// Returns `Ok(None)` if `raw` is null.
+    ///
+    /// # Safety
+    ///
+    /// If non-null, `raw` must point to a nul-terminated C string that remains
+    /// valid for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn from_ptr(raw: *const c_char) -> Result<Option<CUtf8Ref<'a>>, Utf8Error> {
+        if raw.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(CUtf8Ref::new(CUtf8::from_ptr(raw)?)))
+        }
+    }
+
+    /// Returns a thin pointer to the raw C string without checking UTF-8
+    /// validity.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a nul-terminated C string that remains valid for
+    /// the lifetime `'a`, and the bytes up to the first nul must be valid
+    /// UTF-8.
+    #[inline]
+    pub const unsafe fn from_ptr_unchecked(raw: NonNull<c_char>) -> CUtf8Ref<'a> {
+        CUtf8Ref { ptr: raw, marker: PhantomData }
+    }
+
+    /// Returns the pointer to the start of the C string.
+    #[inline]
+    pub const fn as_ptr(self) -> *const c_char {
+        self.ptr.as_ptr() as *const c_char
+    }
+
+    /// Returns the pointer held by `c`, or null if `c` is `None`.
+    #[inline]
+    pub fn option_as_ptr(c: Option<CUtf8Ref<'a>>) -> *const c_char {
+        match c {
+            Some(c) => c.as_ptr(),
+            None => ptr::null(),
+        }
+    }
+
+    /// Returns the C string as a fat `&CUtf8`, computing its length.
+    ///
+    /// This takes time linear in the length of the string.
+    #[inline]
+    pub fn as_c_utf8(self) -> &'a CUtf8 {
+        unsafe { CUtf8::from_ptr_unchecked(self.as_ptr()) }
+    }
+
+    /// Returns the C string as a normal UTF-8 encoded string, computing its
+    /// length.
+    #[inline]
+    pub fn as_str(self) -> &'a str {
+        self.as_c_utf8().as_str()
+    }
+
+    /// Returns the number of bytes before the nul terminator, computing it
+    /// with `strlen`.
+    #[inline]
+    pub fn len(self) -> usize {
+        self.as_c_utf8().len()
+    }
+
+    /// Returns `true` if the first byte is the nul terminator.
+    ///
+    /// Unlike [`len`](#method.len), this takes constant time.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        unsafe { *self.as_ptr() == 0 }
+    }
+}
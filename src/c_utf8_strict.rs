@@ -0,0 +1,245 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str::{self, Utf8Error};
+
+#[cfg(feature = "std")]
+use std::ffi::CStr;
+
+use c_char;
+use c_utf8::CUtf8;
+use error::Error;
+
+/// A [`CUtf8`](struct.CUtf8.html) with the additional guarantee of containing
+/// exactly one nul byte, which is its last byte.
+///
+/// C APIs stop reading at the first nul byte. Since this type has no interior
+/// nul bytes, C sees the same string as Rust does.
+///
+/// # Guarantees
+///
+/// In addition to those of [`CUtf8`](struct.CUtf8.html), this type guarantees
+/// that instances:
+///
+/// - Contain no nul byte other than the trailing one.
+///
+/// Any `&CUtf8Strict` can be used as a `&CUtf8` at no cost, either through
+/// [`as_c_utf8`](#method.as_c_utf8) or through deref coercion.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::{CUtf8, CUtf8Strict, Error};
+///
+/// let strict = CUtf8Strict::from_str("Hello\0").unwrap();
+/// let loose: &CUtf8 = strict;
+/// assert_eq!(loose.as_str(), "Hello");
+///
+/// match CUtf8Strict::from_str("Hel\0lo\0") {
+///     Err(Error::InteriorNul(3)) => {},
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct CUtf8Strict(CUtf8);
+
+mod try_from {
+    use super::*;
+    use core::convert::TryFrom;
+
+    impl<'a> TryFrom<&'a [u8]> for &'a CUtf8Strict {
+        type Error = Error;
+
+        #[inline]
+        fn try_from(bytes: &[u8]) -> Result<&CUtf8Strict, Self::Error> {
+            CUtf8Strict::from_bytes(bytes)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<'a> TryFrom<&'a CStr> for &'a CUtf8Strict {
+        type Error = Utf8Error;
+
+        #[inline]
+        fn try_from(c: &CStr) -> Result<&CUtf8Strict, Self::Error> {
+            CUtf8Strict::from_c_str(c)
+        }
+    }
+
+    impl<'a> TryFrom<&'a str> for &'a CUtf8Strict {
+        type Error = Error;
+
+        #[inline]
+        fn try_from(s: &str) -> Result<&CUtf8Strict, Self::Error> {
+            CUtf8Strict::from_str(s)
+        }
+    }
+
+    impl<'a> TryFrom<&'a CUtf8> for &'a CUtf8Strict {
+        type Error = Error;
+
+        #[inline]
+        fn try_from(c: &CUtf8) -> Result<&CUtf8Strict, Self::Error> {
+            CUtf8Strict::from_c_utf8(c)
+        }
+    }
+}
+
+impl Deref for CUtf8Strict {
+    type Target = CUtf8;
+
+    #[inline]
+    fn deref(&self) -> &CUtf8 {
+        self.as_c_utf8()
+    }
+}
+
+impl AsRef<CUtf8> for CUtf8Strict {
+    #[inline]
+    fn as_ref(&self) -> &CUtf8 {
+        self
+    }
+}
+
+impl AsRef<str> for CUtf8Strict {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsRef<CStr> for CUtf8Strict {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl AsRef<[u8]> for CUtf8Strict {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> From<&'a CUtf8Strict> for &'a CUtf8 {
+    #[inline]
+    fn from(c: &CUtf8Strict) -> &CUtf8 {
+        c
+    }
+}
+
+impl fmt::Debug for CUtf8Strict {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for CUtf8Strict {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'a> Default for &'a CUtf8Strict {
+    #[inline]
+    fn default() -> &'a CUtf8Strict { CUtf8Strict::EMPTY }
+}
+
+impl CUtf8Strict {
+    /// A static &#8220;empty&#8221; borrowed C string.
+    ///
+    /// The string is still nul-terminated, which makes it safe to pass to C.
+    pub const EMPTY: &'static CUtf8Strict = unsafe {
+        CUtf8Strict::from_c_utf8_unchecked(CUtf8::EMPTY)
+    };
+
+    /// Returns a C string containing `bytes`, or an error if a nul byte is
+    /// anywhere but the end or if the bytes are not encoded as UTF-8.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&CUtf8Strict, Error> {
+        CUtf8Strict::from_str(str::from_utf8(bytes)?)
+    }
+
+    /// Returns the UTF-8 string if its only nul byte is at the end.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn from_str(s: &str) -> Result<&CUtf8Strict, Error> {
+        CUtf8Strict::from_c_utf8(CUtf8::from_str(s)?)
+    }
+
+    /// Returns the C string if it has no nul byte before its end.
+    #[inline]
+    pub fn from_c_utf8(c: &CUtf8) -> Result<&CUtf8Strict, Error> {
+        match c.interior_nul_position() {
+            Some(pos) => Err(Error::InteriorNul(pos)),
+            None => unsafe { Ok(CUtf8Strict::from_c_utf8_unchecked(c)) },
+        }
+    }
+
+    /// Returns the C string if it is valid UTF-8.
+    ///
+    /// A [`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html) never
+    /// has interior nul bytes, so only the encoding needs to be checked.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_c_str(c: &CStr) -> Result<&CUtf8Strict, Utf8Error> {
+        let c = CUtf8::from_c_str(c)?;
+        unsafe { Ok(CUtf8Strict::from_c_utf8_unchecked(c)) }
+    }
+
+    /// Returns the raw C string if it is valid UTF-8 up to the first nul byte.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a nul-terminated C string that remains valid for
+    /// the lifetime `'a`.
+    #[inline]
+    pub unsafe fn from_ptr<'a>(raw: *const c_char) -> Result<&'a CUtf8Strict, Utf8Error> {
+        // The string ends at the first nul byte, so it cannot have another
+        Ok(CUtf8Strict::from_c_utf8_unchecked(CUtf8::from_ptr(raw)?))
+    }
+
+    /// Returns a C string without checking for interior nul bytes.
+    ///
+    /// # Safety
+    ///
+    /// The only nul byte in `c` must be its trailing one.
+    #[inline]
+    pub const unsafe fn from_c_utf8_unchecked(c: &CUtf8) -> &CUtf8Strict {
+        &*(c as *const CUtf8 as *const CUtf8Strict)
+    }
+
+    /// Returns a C string without checking UTF-8 validity or the position of
+    /// nul bytes.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8 and their only nul byte must be the last.
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(b: &[u8]) -> &CUtf8Strict {
+        CUtf8Strict::from_c_utf8_unchecked(CUtf8::from_bytes_unchecked(b))
+    }
+
+    /// Returns a C string without checking the position of nul bytes.
+    ///
+    /// # Safety
+    ///
+    /// The only nul byte in `s` must be its last byte.
+    #[inline]
+    pub unsafe fn from_str_unchecked(s: &str) -> &CUtf8Strict {
+        CUtf8Strict::from_c_utf8_unchecked(CUtf8::from_str_unchecked(s))
+    }
+
+    /// Returns `self` as a C string that may have interior nul bytes.
+    ///
+    /// This is free, since the guarantees of `CUtf8` are a subset of those of
+    /// `CUtf8Strict`.
+    #[inline]
+    pub fn as_c_utf8(&self) -> &CUtf8 {
+        &self.0
+    }
+}
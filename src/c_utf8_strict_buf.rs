@@ -0,0 +1,323 @@
+use std::borrow::{Borrow, ToOwned};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use c_utf8::CUtf8;
+use c_utf8_buf::CUtf8Buf;
+use c_utf8_strict::CUtf8Strict;
+use error::{Error, FromOwnedError};
+use ext::Ext;
+
+/// An owned UTF-8 encoded C string with no interior nul bytes (akin to
+/// [`CString`]).
+///
+/// This is the owned counterpart to [`CUtf8Strict`], in the same way that
+/// [`CUtf8Buf`] is the owned counterpart to [`CUtf8`].
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::{CUtf8Buf, CUtf8StrictBuf, Error};
+///
+/// let mut buf = CUtf8StrictBuf::from_string("Hello".into()).unwrap();
+/// buf.push_str(", world!").unwrap();
+///
+/// match buf.push_str("\0oops") {
+///     Err(Error::InteriorNul(13)) => {},
+///     _ => unreachable!(),
+/// }
+///
+/// let loose: CUtf8Buf = buf.into_c_utf8_buf();
+/// assert_eq!(loose.as_str(), "Hello, world!");
+/// ```
+///
+/// [`CString`]:      https://doc.rust-lang.org/std/ffi/struct.CString.html
+/// [`CUtf8Strict`]:  struct.CUtf8Strict.html
+/// [`CUtf8Buf`]:     struct.CUtf8Buf.html
+/// [`CUtf8`]:        struct.CUtf8.html
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct CUtf8StrictBuf(CUtf8Buf);
+
+mod try_from {
+    use super::*;
+    use std::convert::TryFrom;
+
+    impl TryFrom<String> for CUtf8StrictBuf {
+        type Error = FromOwnedError<String>;
+
+        #[inline]
+        fn try_from(s: String) -> Result<CUtf8StrictBuf, Self::Error> {
+            CUtf8StrictBuf::from_string(s)
+        }
+    }
+
+    impl TryFrom<CString> for CUtf8StrictBuf {
+        type Error = FromOwnedError<CString>;
+
+        #[inline]
+        fn try_from(c: CString) -> Result<CUtf8StrictBuf, Self::Error> {
+            CUtf8StrictBuf::from_c_string(c)
+        }
+    }
+
+    impl TryFrom<CUtf8Buf> for CUtf8StrictBuf {
+        type Error = FromOwnedError<CUtf8Buf>;
+
+        #[inline]
+        fn try_from(buf: CUtf8Buf) -> Result<CUtf8StrictBuf, Self::Error> {
+            CUtf8StrictBuf::from_c_utf8_buf(buf)
+        }
+    }
+}
+
+impl Deref for CUtf8StrictBuf {
+    type Target = CUtf8Strict;
+
+    #[inline]
+    fn deref(&self) -> &CUtf8Strict {
+        unsafe { CUtf8Strict::from_c_utf8_unchecked(&self.0) }
+    }
+}
+
+impl fmt::Debug for CUtf8StrictBuf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl fmt::Display for CUtf8StrictBuf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl Borrow<CUtf8Strict> for CUtf8StrictBuf {
+    #[inline]
+    fn borrow(&self) -> &CUtf8Strict { self }
+}
+
+impl Borrow<str> for CUtf8StrictBuf {
+    #[inline]
+    fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl AsRef<CUtf8Strict> for CUtf8StrictBuf {
+    #[inline]
+    fn as_ref(&self) -> &CUtf8Strict { self }
+}
+
+impl AsRef<CUtf8> for CUtf8StrictBuf {
+    #[inline]
+    fn as_ref(&self) -> &CUtf8 { self }
+}
+
+impl ToOwned for CUtf8Strict {
+    type Owned = CUtf8StrictBuf;
+
+    #[inline]
+    fn to_owned(&self) -> CUtf8StrictBuf {
+        CUtf8StrictBuf(self.as_c_utf8().to_owned())
+    }
+}
+
+impl From<&CUtf8Strict> for CUtf8StrictBuf {
+    #[inline]
+    fn from(c: &CUtf8Strict) -> CUtf8StrictBuf {
+        c.to_owned()
+    }
+}
+
+impl From<CUtf8StrictBuf> for CUtf8Buf {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> CUtf8Buf {
+        buf.into_c_utf8_buf()
+    }
+}
+
+/// This does not copy unless the buffer has excess capacity.
+impl From<CUtf8StrictBuf> for CString {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> CString {
+        buf.into_c_string()
+    }
+}
+
+impl From<CUtf8StrictBuf> for Box<CStr> {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> Box<CStr> {
+        buf.into_c_string().into_boxed_c_str()
+    }
+}
+
+impl From<CUtf8StrictBuf> for Rc<CStr> {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> Rc<CStr> {
+        buf.into_c_string().into()
+    }
+}
+
+impl From<CUtf8StrictBuf> for Arc<CStr> {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> Arc<CStr> {
+        buf.into_c_string().into()
+    }
+}
+
+impl From<CUtf8StrictBuf> for String {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> String {
+        buf.into_string()
+    }
+}
+
+impl From<CUtf8StrictBuf> for Vec<u8> {
+    #[inline]
+    fn from(buf: CUtf8StrictBuf) -> Vec<u8> {
+        buf.into_bytes()
+    }
+}
+
+impl CUtf8StrictBuf {
+    /// Creates a new empty `CUtf8StrictBuf`.
+    #[inline]
+    pub fn new() -> CUtf8StrictBuf {
+        CUtf8StrictBuf(CUtf8Buf::new())
+    }
+
+    /// Creates a new C string from a UTF-8 string, appending a nul terminator
+    /// if one doesn't already exist, or hands back `s` if a nul byte is found
+    /// anywhere else.
+    #[inline]
+    pub fn from_string(s: String) -> Result<CUtf8StrictBuf, FromOwnedError<String>> {
+        let had_nul = s.ends_with('\0');
+        CUtf8StrictBuf::from_c_utf8_buf(CUtf8Buf::from_string(s)).map_err(|err| {
+            err.map(|buf| if had_nul {
+                buf.into_string_with_nul()
+            } else {
+                buf.into_string()
+            })
+        })
+    }
+
+    /// Converts the C string into one that is known to have no interior nul
+    /// bytes, or hands back `buf` with the position of the first one.
+    #[inline]
+    pub fn from_c_utf8_buf(buf: CUtf8Buf) -> Result<CUtf8StrictBuf, FromOwnedError<CUtf8Buf>> {
+        match buf.interior_nul_position() {
+            Some(pos) => Err(FromOwnedError::new(buf, Error::InteriorNul(pos))),
+            None => Ok(CUtf8StrictBuf(buf)),
+        }
+    }
+
+    /// Creates a new C string from an owned C string, or hands back `c` if it
+    /// is not encoded as UTF-8.
+    ///
+    /// A `CString` never has interior nul bytes, so only the encoding needs to
+    /// be checked. On success, the allocation of `c` is reused.
+    #[inline]
+    pub fn from_c_string(c: CString) -> Result<CUtf8StrictBuf, FromOwnedError<CString>> {
+        let buf = CUtf8Buf::from_c_string(c)?;
+        unsafe { Ok(CUtf8StrictBuf::from_c_utf8_buf_unchecked(buf)) }
+    }
+
+    /// Converts the C string without checking for interior nul bytes.
+    ///
+    /// # Safety
+    ///
+    /// The only nul byte in `buf` must be its trailing one.
+    #[inline]
+    pub unsafe fn from_c_utf8_buf_unchecked(buf: CUtf8Buf) -> CUtf8StrictBuf {
+        CUtf8StrictBuf(buf)
+    }
+
+    /// Appends a given string slice onto the end of this `CUtf8StrictBuf`,
+    /// or returns an error if `s` contains a nul byte.
+    ///
+    /// On error, `self` is left unchanged.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> Result<(), Error> {
+        match s.first_nul() {
+            Some(pos) => Err(Error::InteriorNul(self.len() + pos)),
+            None => {
+                self.0.push_str(s);
+                Ok(())
+            },
+        }
+    }
+
+    /// Appends the given `char` to the end of this `CUtf8StrictBuf`, or
+    /// returns an error if it is the nul character.
+    ///
+    /// On error, `self` is left unchanged.
+    #[inline]
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        if c == '\0' {
+            Err(Error::InteriorNul(self.len()))
+        } else {
+            self.0.push(c);
+            Ok(())
+        }
+    }
+
+    /// Converts `self` into a C string that may have interior nul bytes.
+    ///
+    /// This is free, since the guarantees of `CUtf8Buf` are a subset of those
+    /// of `CUtf8StrictBuf`.
+    #[inline]
+    pub fn into_c_utf8_buf(self) -> CUtf8Buf {
+        self.0
+    }
+
+    /// Converts `self` into a
+    /// [`CString`](https://doc.rust-lang.org/std/ffi/struct.CString.html).
+    ///
+    /// This does not copy unless the buffer has excess capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_utf8::CUtf8StrictBuf;
+    ///
+    /// let buf = CUtf8StrictBuf::from_string("hello".into()).unwrap();
+    /// let c = buf.into_c_string();
+    ///
+    /// assert_eq!(c.as_bytes_with_nul(), b"hello\0");
+    /// ```
+    #[inline]
+    pub fn into_c_string(self) -> CString {
+        let bytes = self.into_bytes_with_nul();
+        unsafe { CString::from_vec_with_nul_unchecked(bytes) }
+    }
+
+    /// Converts `self` into a native UTF-8 encoded Rust
+    /// [`String`](https://doc.rust-lang.org/std/string/struct.String.html).
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0.into_string()
+    }
+
+    /// Converts `self` into a native UTF-8 encoded Rust
+    /// [`String`](https://doc.rust-lang.org/std/string/struct.String.html) with
+    /// a trailing 0 byte.
+    #[inline]
+    pub fn into_string_with_nul(self) -> String {
+        self.0.into_string_with_nul()
+    }
+
+    /// Converts `self` into its underlying bytes.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0.into_bytes()
+    }
+
+    /// Converts `self` into its underlying bytes with a trailing 0 byte.
+    #[inline]
+    pub fn into_bytes_with_nul(self) -> Vec<u8> {
+        self.0.into_bytes_with_nul()
+    }
+}
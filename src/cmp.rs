@@ -0,0 +1,152 @@
+// Comparisons between C strings and other string types. All comparisons are
+// over the string before the trailing nul byte, and a `CStr` is compared by the
+// bytes before its nul byte.
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+#[cfg(feature = "std")]
+use std::ffi::{CStr, CString};
+
+use c_utf8::CUtf8;
+use c_utf8_array::CUtf8Array;
+#[cfg(feature = "std")]
+use c_utf8_buf::CUtf8Buf;
+
+/// The bytes that a string type is compared by.
+trait Content {
+    fn content(&self) -> &[u8];
+}
+
+impl<T: ?Sized + Content> Content for &T {
+    #[inline]
+    fn content(&self) -> &[u8] { (**self).content() }
+}
+
+impl Content for CUtf8 {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl<const N: usize> Content for CUtf8Array<N> {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl Content for str {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl Content for CUtf8Buf {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl Content for Box<CUtf8> {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Content for Cow<'a, CUtf8> {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl Content for String {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl Content for CStr {
+    #[inline]
+    fn content(&self) -> &[u8] { self.to_bytes() }
+}
+
+#[cfg(feature = "std")]
+impl Content for CString {
+    #[inline]
+    fn content(&self) -> &[u8] { self.as_bytes() }
+}
+
+macro_rules! impl_cmp {
+    ($([$($g:tt)*] $lhs:ty, $rhs:ty;)+) => { $(
+        impl<$($g)*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self.content() == other.content()
+            }
+        }
+
+        impl<$($g)*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                self.content() == other.content()
+            }
+        }
+
+        impl<$($g)*> PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                self.content().partial_cmp(other.content())
+            }
+        }
+
+        impl<$($g)*> PartialOrd<$lhs> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                self.content().partial_cmp(other.content())
+            }
+        }
+    )+ }
+}
+
+impl_cmp! {
+    [] CUtf8, str;
+    ['a] CUtf8, &'a str;
+
+    [const N: usize] CUtf8Array<N>, CUtf8;
+    ['a, const N: usize] CUtf8Array<N>, &'a CUtf8;
+    [const N: usize] CUtf8Array<N>, str;
+    ['a, const N: usize] CUtf8Array<N>, &'a str;
+}
+
+#[cfg(feature = "std")]
+impl_cmp! {
+    [] CUtf8, String;
+    ['a] &'a CUtf8, String;
+    [] CUtf8, CStr;
+    ['a] CUtf8, &'a CStr;
+    [] CUtf8, CString;
+    ['a] &'a CUtf8, CString;
+    ['a] CUtf8, Cow<'a, CUtf8>;
+    ['a, 'b] &'b CUtf8, Cow<'a, CUtf8>;
+    [] CUtf8, Box<CUtf8>;
+    ['a] &'a CUtf8, Box<CUtf8>;
+
+    [] CUtf8Buf, CUtf8;
+    ['a] CUtf8Buf, &'a CUtf8;
+    [] CUtf8Buf, str;
+    ['a] CUtf8Buf, &'a str;
+    [] CUtf8Buf, String;
+    [] CUtf8Buf, CStr;
+    ['a] CUtf8Buf, &'a CStr;
+    [] CUtf8Buf, CString;
+    ['a] CUtf8Buf, Cow<'a, CUtf8>;
+    [] CUtf8Buf, Box<CUtf8>;
+
+    [] Box<CUtf8>, str;
+    ['a] Box<CUtf8>, &'a str;
+    [] Box<CUtf8>, String;
+    [] Box<CUtf8>, CStr;
+    ['a] Box<CUtf8>, &'a CStr;
+    [] Box<CUtf8>, CString;
+    ['a] Box<CUtf8>, Cow<'a, CUtf8>;
+}
@@ -0,0 +1,50 @@
+/// The result of copying a [`CUtf8`](struct.CUtf8.html) into a C buffer via
+/// [`copy_to`](struct.CUtf8.html#method.copy_to) and related methods.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// let mut buf = [0u8; 3];
+/// let copied = c_utf8!("héllo").copy_to_bytes(&mut buf);
+///
+/// // "é" does not fit within the space before the nul, so it is not split
+/// assert_eq!(&buf[..2], b"h\0");
+/// assert_eq!(copied.written(), 1);
+/// assert_eq!(copied.truncated(), 5);
+/// assert_eq!(copied.required(), 7);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Copied {
+    pub(crate) written: usize,
+    pub(crate) required: usize,
+}
+
+impl Copied {
+    /// Returns the number of string bytes written, not counting the trailing
+    /// nul byte.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Returns the buffer length, including the trailing nul byte, that would
+    /// have been needed to copy the entire string.
+    #[inline]
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the number of string bytes that did not fit.
+    #[inline]
+    pub fn truncated(&self) -> usize {
+        self.required - 1 - self.written
+    }
+
+    /// Returns `true` if the string did not fit entirely.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated() != 0
+    }
+}
@@ -2,19 +2,72 @@ use core::fmt;
 use core::str::Utf8Error;
 
 #[cfg(feature = "std")]
-use std::ffi::FromBytesWithNulError;
+use std::error;
+
+#[cfg(feature = "std")]
+use std::ffi::{FromBytesUntilNulError, FromBytesWithNulError, NulError};
+
+#[cfg(feature = "std")]
+use std::io;
 
 /// The error for converting types to [`CUtf8`](struct.CUtf8.html).
-#[derive(Clone, Debug)]
+///
+/// Each variant records why the input was rejected and, where applicable, the
+/// byte offset at which the problem was found. The offset is also available
+/// through [`position`](#method.position).
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::{CUtf8, Error};
+///
+/// assert_eq!(CUtf8::from_bytes(b"abc"), Err(Error::MissingNul));
+///
+/// let err = CUtf8::from_bytes(b"ab\xFF\0").unwrap_err();
+/// assert_eq!(err.position(), Some(2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
-    /// An error indicating that the nul byte was not at the end.
-    Nul,
+    /// An error indicating that the input did not end with a nul byte.
+    MissingNul,
+    /// An error indicating that a nul byte was found before the end, at the
+    /// given byte position.
+    InteriorNul(usize),
+    /// An error indicating that no nul byte was found within the given maximum
+    /// number of bytes.
+    TooLong(usize),
+    /// An error indicating that a string did not fit within a fixed-size
+    /// buffer that can hold the given number of bytes, not counting the nul
+    /// terminator.
+    CapacityExceeded(usize),
     /// An error indicating that input bytes were not encoded as UTF-8.
+    ///
+    /// The input was valid up to
+    /// [`Utf8Error::valid_up_to`](https://doc.rust-lang.org/std/str/struct.Utf8Error.html#method.valid_up_to).
     Utf8(Utf8Error),
 }
 
 static NUL_ERROR: &str = "Missing nul byte at the end of the string";
 
+impl Error {
+    /// Returns the byte position in the input at which the error was found,
+    /// if the error refers to a specific byte.
+    ///
+    /// For [`TooLong`](#variant.TooLong), this is the maximum number of bytes.
+    /// For [`Utf8`](#variant.Utf8), this is the length of the valid prefix.
+    #[inline]
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            Error::MissingNul => None,
+            Error::InteriorNul(pos) => Some(pos),
+            Error::TooLong(max) => Some(max),
+            Error::CapacityExceeded(_) => None,
+            Error::Utf8(err) => Some(err.valid_up_to()),
+        }
+    }
+}
+
 impl From<Utf8Error> for Error {
     #[inline]
     fn from(err: Utf8Error) -> Error {
@@ -25,32 +78,133 @@ impl From<Utf8Error> for Error {
 #[cfg(feature = "std")]
 impl From<FromBytesWithNulError> for Error {
     #[inline]
-    fn from(_: FromBytesWithNulError) -> Error {
-        Error::Nul
+    fn from(err: FromBytesWithNulError) -> Error {
+        match err {
+            FromBytesWithNulError::InteriorNul { position } => {
+                Error::InteriorNul(position)
+            },
+            FromBytesWithNulError::NotNulTerminated => Error::MissingNul,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FromBytesUntilNulError> for Error {
+    #[inline]
+    fn from(_: FromBytesUntilNulError) -> Error {
+        Error::MissingNul
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NulError> for Error {
+    #[inline]
+    fn from(err: NulError) -> Error {
+        Error::InteriorNul(err.nul_position())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for io::Error {
+    #[inline]
+    fn from(err: Error) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Error::Nul => NUL_ERROR.fmt(f),
+            Error::MissingNul => NUL_ERROR.fmt(f),
+            Error::InteriorNul(pos) => {
+                write!(f, "Nul byte found at position {} before the end of the string", pos)
+            },
+            Error::TooLong(max) => {
+                write!(f, "No nul byte found within the first {} bytes", max)
+            },
+            Error::CapacityExceeded(cap) => {
+                write!(f, "String does not fit within a capacity of {} bytes", cap)
+            },
             Error::Utf8(err) => err.fmt(f),
         }
     }
 }
 
+/// The error for converting an owned value into a C string, which hands back
+/// the original value so that its allocation is not lost.
+///
+/// This is akin to
+/// [`FromUtf8Error`](https://doc.rust-lang.org/std/string/struct.FromUtf8Error.html).
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::CUtf8Buf;
+///
+/// let bytes = vec![b'a', 0xFF];
+/// let err = CUtf8Buf::from_vec(bytes).unwrap_err();
+///
+/// assert_eq!(err.error().position(), Some(1));
+/// assert_eq!(err.into_inner(), [b'a', 0xFF]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FromOwnedError<T> {
+    inner: T,
+    error: Error,
+}
+
+#[cfg(feature = "std")]
+impl<T> FromOwnedError<T> {
+    #[inline]
+    pub(crate) fn new(inner: T, error: Error) -> FromOwnedError<T> {
+        FromOwnedError { inner, error }
+    }
+
+    /// Returns the reason for why the conversion failed.
+    #[inline]
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Returns a reference to the value that failed to be converted.
+    #[inline]
+    pub fn as_inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the value that failed to be converted, without copying.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    #[inline]
+    pub(crate) fn map<U, F: FnOnce(T) -> U>(self, f: F) -> FromOwnedError<U> {
+        FromOwnedError { inner: f(self.inner), error: self.error }
+    }
+}
+
 #[cfg(feature = "std")]
-impl ::std::error::Error for Error {
+impl<T> fmt::Display for FromOwnedError<T> {
     #[inline]
-    fn description(&self) -> &str {
-        match *self {
-            Error::Nul => NUL_ERROR,
-            Error::Utf8(ref err) => err.description(),
-        }
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)
     }
+}
 
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> error::Error for FromOwnedError<T> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for Error {
     #[inline]
-    fn cause(&self) -> Option<&::std::error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
             Error::Utf8(ref err) => Some(err),
             _ => None,
@@ -1,5 +1,7 @@
 pub trait Ext {
     fn is_nul_terminated(&self) -> bool;
+
+    fn first_nul(&self) -> Option<usize>;
 }
 
 impl Ext for str {
@@ -7,6 +9,11 @@ impl Ext for str {
     fn is_nul_terminated(&self) -> bool {
         self.as_bytes().is_nul_terminated()
     }
+
+    #[inline]
+    fn first_nul(&self) -> Option<usize> {
+        self.as_bytes().first_nul()
+    }
 }
 
 impl Ext for [u8] {
@@ -14,4 +21,15 @@ impl Ext for [u8] {
     fn is_nul_terminated(&self) -> bool {
         self.last().cloned() == Some(0)
     }
+
+    #[inline]
+    fn first_nul(&self) -> Option<usize> {
+        self.iter().position(|&b| b == 0)
+    }
+}
+
+/// Returns whether `bytes` end with a nul byte, usable within a constant
+/// expression.
+pub const fn ends_with_nul(bytes: &[u8]) -> bool {
+    matches!(bytes.last(), Some(0))
 }
@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+use c_utf8::CUtf8;
+use c_utf8_buf::CUtf8Buf;
+use c_utf8_strict::CUtf8Strict;
+use c_utf8_strict_buf::CUtf8StrictBuf;
+
+/// A conversion into a [`CUtf8`](struct.CUtf8.html) that only allocates when
+/// the input is not already a nul-terminated UTF-8 string.
+///
+/// This allows for functions to accept any kind of string while only paying
+/// for conversion when it is needed.
+///
+/// Borrowed inputs stay borrowed when possible:
+///
+/// - `&CUtf8`, `&CUtf8Buf` and `&CUtf8Strict` are always borrowed.
+///
+/// - `&str` and `&String` are borrowed if they end with a nul byte.
+///   Otherwise, they are copied and terminated.
+///
+/// Owned inputs, such as `String`, `CUtf8Buf` and `Box<CUtf8>`, are moved
+/// into the result without copying their contents.
+///
+/// `&CStr` is not accepted since it may not be valid UTF-8. Convert it with
+/// [`CUtf8::from_c_str`](struct.CUtf8.html#method.from_c_str), or with
+/// [`CUtf8::from_c_str_lossy`](struct.CUtf8.html#method.from_c_str_lossy) to
+/// replace invalid sequences.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// use std::borrow::Cow;
+/// use c_utf8::{CUtf8, IntoCUtf8};
+///
+/// fn set_title<'a, S: IntoCUtf8<'a>>(title: S) -> Cow<'a, CUtf8> {
+///     let title = title.into_c_utf8();
+///     // pass `title.as_ptr()` to C...
+///     title
+/// }
+///
+/// assert!(matches!(set_title(c_utf8!("static")), Cow::Borrowed(_)));
+/// assert!(matches!(set_title("terminated\0"), Cow::Borrowed(_)));
+/// assert!(matches!(set_title("plain"), Cow::Owned(_)));
+/// assert!(matches!(set_title(String::from("owned")), Cow::Owned(_)));
+/// # }
+/// ```
+pub trait IntoCUtf8<'a> {
+    /// Converts `self` into a C string, borrowing when possible.
+    fn into_c_utf8(self) -> Cow<'a, CUtf8>;
+}
+
+impl<'a> IntoCUtf8<'a> for &'a CUtf8 {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for &'a CUtf8Buf {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for &'a CUtf8Strict {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for &'a str {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        match CUtf8::from_str(self) {
+            Ok(c) => Cow::Borrowed(c),
+            Err(_) => Cow::Owned(CUtf8Buf::from(self)),
+        }
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for &'a String {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        self.as_str().into_c_utf8()
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for CUtf8Buf {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Owned(self)
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for CUtf8StrictBuf {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Owned(self.into_c_utf8_buf())
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for String {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Owned(CUtf8Buf::from_string(self))
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for Box<CUtf8> {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        Cow::Owned(self.into())
+    }
+}
+
+impl<'a> IntoCUtf8<'a> for Cow<'a, CUtf8> {
+    #[inline]
+    fn into_c_utf8(self) -> Cow<'a, CUtf8> {
+        self
+    }
+}
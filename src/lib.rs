@@ -8,6 +8,12 @@
 //! - Encoded as valid [UTF-8], allowing for passing around native Rust [`str`]
 //!   strings with ease.
 //!
+//! The [`CUtf8Strict`] and [`CUtf8StrictBuf`] types additionally guarantee that
+//! the trailing nul is the _only_ nul byte, so C sees the entire string.
+//!
+//! [`CUtf8Array`] provides an owned, fixed-capacity string stored inline,
+//! including without `std`.
+//!
 //! # Usage
 //!
 //! This crate is available [on crates.io](https://crates.io/crates/c_utf8) and
@@ -58,15 +64,17 @@
 //!     <img src="https://buymecoffee.intm.org/img/button-paypal-white.png" alt="Buy me a coffee" height="35">
 //! </a>
 //!
-//! [UTF-8]:      https://en.wikipedia.org/wiki/UTF-8
-//! [c_str]:      https://en.wikipedia.org/wiki/Null-terminated_string
-//! [`str`]:      https://doc.rust-lang.org/std/primitive.str.html
-//! [`CUtf8`]:    struct.CUtf8.html
-//! [`CUtf8Buf`]: struct.CUtf8Buf.html
+//! [UTF-8]:            https://en.wikipedia.org/wiki/UTF-8
+//! [c_str]:            https://en.wikipedia.org/wiki/Null-terminated_string
+//! [`str`]:            https://doc.rust-lang.org/std/primitive.str.html
+//! [`CUtf8`]:          struct.CUtf8.html
+//! [`CUtf8Buf`]:       struct.CUtf8Buf.html
+//! [`CUtf8Array`]:     struct.CUtf8Array.html
+//! [`CUtf8Strict`]:    struct.CUtf8Strict.html
+//! [`CUtf8StrictBuf`]: struct.CUtf8StrictBuf.html
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(feature = "try_from", feature(try_from))]
 #![cfg_attr(all(test, nightly), feature(test))]
 
 #[cfg(all(test, nightly))]
@@ -81,10 +89,15 @@ use std as core;
 ///
 /// # Usage
 ///
-/// Although the input string can have a 0 byte, it is **highly recommended** to
-/// not have one. This is because C APIs will only work with the memory up to
-/// the first 0 byte. In the future, it will be very likely be a **hard error**
-/// to have a 0 byte within the string literal.
+/// The input string must not contain a 0 byte. C APIs only work with the memory
+/// up to the first 0 byte, so an interior 0 byte would silently cut the string
+/// short. This is checked at compile time:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// let string = c_utf8!("Hello\0there!");
+/// # }
+/// ```
 ///
 /// # Examples
 ///
@@ -108,34 +121,72 @@ use std as core;
 /// ```
 /// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8; fn main() {
 /// static APP_NAME: &CUtf8 = c_utf8!(env!("CARGO_PKG_NAME"));
+/// const GREETING: &CUtf8 = c_utf8!("Hello");
 ///
 /// assert_eq!(APP_NAME.as_str_with_nul(), "c_utf8\0");
+/// assert_eq!(GREETING.as_str_with_nul(), "Hello\0");
+/// # }
+/// ```
+///
+/// A thin [`CUtf8Ref`](struct.CUtf8Ref.html) for use in `extern "C"`
+/// signatures and `#[repr(C)]` structs can also be made in a constant:
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; use c_utf8::CUtf8Ref; fn main() {
+/// const NAME: CUtf8Ref = CUtf8Ref::new(c_utf8!("name"));
+///
+/// assert_eq!(NAME.as_str(), "name");
 /// # }
 /// ```
 ///
 /// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
 #[macro_export]
 macro_rules! c_utf8 {
-    ($s:expr) => {
-        unsafe {
-            // An internal type that allows for converting static Rust string
-            // slices into static CUtf8 slices within a constant expression
-            union _Ref<'a> { s: &'a str, c: &'a $crate::CUtf8 }
-            _Ref { s: concat!($s, "\0") }.c
-        }
-    }
+    ($s:expr) => {{
+        // Evaluating within a constant turns an interior nul byte into a
+        // compile-time error
+        const C_UTF8: &'static $crate::CUtf8 = $crate::CUtf8::__from_literal(
+            concat!($s, "\0")
+        );
+        C_UTF8
+    }}
 }
 
 #[cfg(feature = "std")]
 mod c_utf8_buf;
+#[cfg(feature = "std")]
+mod c_utf8_strict_buf;
+#[cfg(feature = "std")]
+mod into_c_utf8;
 mod c_utf8;
+mod c_utf8_array;
+mod c_utf8_index;
+mod c_utf8_ref;
+mod c_utf8_strict;
+mod cmp;
+mod copied;
 mod error;
 mod ext;
+mod malloc_c_utf8;
+mod pattern;
+mod with_c_utf8;
 
 #[cfg(feature = "std")]
 pub use self::c_utf8_buf::*;
+#[cfg(feature = "std")]
+pub use self::c_utf8_strict_buf::*;
+#[cfg(feature = "std")]
+pub use self::into_c_utf8::*;
 pub use self::c_utf8::*;
+pub use self::c_utf8_array::*;
+pub use self::c_utf8_index::*;
+pub use self::c_utf8_ref::*;
+pub use self::c_utf8_strict::*;
+pub use self::copied::*;
 pub use self::error::*;
+pub use self::malloc_c_utf8::*;
+pub use self::pattern::*;
+pub use self::with_c_utf8::*;
 
 /// Equivalent to C's `char` type.
 #[allow(non_camel_case_types)]
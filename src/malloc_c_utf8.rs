@@ -0,0 +1,204 @@
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Deref;
+use core::ptr::NonNull;
+
+#[cfg(feature = "std")]
+use c_utf8_buf::CUtf8Buf;
+
+use c_char;
+use c_utf8::CUtf8;
+use error::Error;
+
+extern "C" {
+    fn free(ptr: *mut c_void);
+}
+
+/// A way of freeing strings allocated by C, used by
+/// [`MallocCUtf8`](struct.MallocCUtf8.html).
+///
+/// # Examples
+///
+/// Strings from GLib must be freed with `g_free`:
+///
+/// ```
+/// use c_utf8::{c_char, Dealloc, MallocCUtf8};
+///
+/// # mod glib { pub unsafe fn g_free(_: *mut c_utf8::c_char) {} }
+/// struct GFree;
+///
+/// impl Dealloc for GFree {
+///     unsafe fn dealloc(ptr: *mut c_char) {
+///         glib::g_free(ptr);
+///     }
+/// }
+///
+/// type GString = MallocCUtf8<GFree>;
+/// ```
+pub trait Dealloc {
+    /// Frees the string at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by the allocator that this deallocator
+    /// pairs with and must not be used afterward.
+    unsafe fn dealloc(ptr: *mut c_char);
+}
+
+/// Deallocates via C's `free`, for strings from `malloc`, `strdup`, `realpath`
+/// and the like.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Free;
+
+impl Dealloc for Free {
+    #[inline]
+    unsafe fn dealloc(ptr: *mut c_char) {
+        free(ptr as *mut c_void);
+    }
+}
+
+/// An owned UTF-8 encoded C string that was allocated by C and is freed by
+/// `D` when dropped.
+///
+/// The string is validated once upon construction and derefs to
+/// [`CUtf8`](struct.CUtf8.html). By default, it is freed via C's `free`.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::{c_char, MallocCUtf8};
+///
+/// extern "C" {
+///     fn strdup(s: *const c_char) -> *mut c_char;
+/// }
+///
+/// let dup: Option<MallocCUtf8> = unsafe {
+///     MallocCUtf8::from_ptr(strdup(b"copy\0".as_ptr() as *const c_char)).unwrap()
+/// };
+///
+/// // `strdup` returns null if it fails to allocate
+/// match dup {
+///     Some(dup) => assert_eq!(dup.as_str(), "copy"),
+///     None => panic!("out of memory"),
+/// }
+/// ```
+pub struct MallocCUtf8<D: Dealloc = Free> {
+    ptr: NonNull<CUtf8>,
+    marker: PhantomData<D>,
+}
+
+impl<D: Dealloc> Drop for MallocCUtf8<D> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { D::dealloc(self.ptr.as_ptr() as *mut c_char) };
+    }
+}
+
+impl<D: Dealloc> Deref for MallocCUtf8<D> {
+    type Target = CUtf8;
+
+    #[inline]
+    fn deref(&self) -> &CUtf8 {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<D: Dealloc> AsRef<CUtf8> for MallocCUtf8<D> {
+    #[inline]
+    fn as_ref(&self) -> &CUtf8 { self }
+}
+
+impl<D: Dealloc> fmt::Debug for MallocCUtf8<D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<D: Dealloc> fmt::Display for MallocCUtf8<D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Dealloc> From<MallocCUtf8<D>> for CUtf8Buf {
+    #[inline]
+    fn from(m: MallocCUtf8<D>) -> CUtf8Buf {
+        m.to_c_utf8_buf()
+    }
+}
+
+impl<D: Dealloc> MallocCUtf8<D> {
+    /// Takes ownership of the raw C string if it is non-null and valid UTF-8
+    /// up to the first nul byte.
+    ///
+    /// Returns `Ok(None)` if `raw` is null, which is how C functions such as
+    /// `strdup` and `realpath` report failure.
+    ///
+    /// If the string is not valid UTF-8, it is freed before returning the
+    /// error, so it is never leaked.
+    ///
+    /// # Safety
+    ///
+    /// If non-null, `raw` must point to a nul-terminated C string that was
+    /// allocated by the allocator `D` pairs with and that is not owned by
+    /// anything else.
+    pub unsafe fn from_ptr(raw: *mut c_char) -> Result<Option<MallocCUtf8<D>>, Error> {
+        if raw.is_null() {
+            return Ok(None);
+        }
+
+        match CUtf8::from_ptr(raw) {
+            Ok(c) => Ok(Some(MallocCUtf8::from_c_utf8(c))),
+            Err(err) => {
+                D::dealloc(raw);
+                Err(err.into())
+            },
+        }
+    }
+
+    /// Takes ownership of the raw C string without checking UTF-8 validity.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for [`from_ptr`](#method.from_ptr) apply, and
+    /// the string must be valid UTF-8 up to its first nul byte.
+    #[inline]
+    pub unsafe fn from_ptr_unchecked(raw: NonNull<c_char>) -> MallocCUtf8<D> {
+        MallocCUtf8::from_c_utf8(CUtf8::from_ptr_unchecked(raw.as_ptr()))
+    }
+
+    #[inline]
+    unsafe fn from_c_utf8(c: &CUtf8) -> MallocCUtf8<D> {
+        MallocCUtf8 {
+            ptr: NonNull::new_unchecked(c as *const CUtf8 as *mut CUtf8),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns `self` as a borrowed C string.
+    #[inline]
+    pub fn as_c_utf8(&self) -> &CUtf8 {
+        self
+    }
+
+    /// Releases ownership of the string without freeing it.
+    #[inline]
+    pub fn into_raw(self) -> *mut c_char {
+        let ptr = self.ptr.as_ptr() as *mut c_char;
+        mem::forget(self);
+        ptr
+    }
+
+    /// Copies the string into a Rust-allocated
+    /// [`CUtf8Buf`](struct.CUtf8Buf.html).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_c_utf8_buf(&self) -> CUtf8Buf {
+        self.as_c_utf8().to_owned()
+    }
+}
@@ -0,0 +1,81 @@
+/// A string pattern accepted by the [`CUtf8`](struct.CUtf8.html) methods that
+/// return the remainder of the string as a `&CUtf8`.
+///
+/// This stands in for the unstable
+/// [`std::str::pattern::Pattern`](https://doc.rust-lang.org/std/str/pattern/trait.Pattern.html)
+/// and is implemented for the same types: `char`, `&str`, `&String`, `char`
+/// arrays and slices, and `FnMut(char) -> bool` closures.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate c_utf8; fn main() {
+/// let s = c_utf8!("--verbose");
+///
+/// assert_eq!(s.strip_prefix("--").unwrap().as_str(), "verbose");
+/// assert_eq!(s.trim_start_matches('-').as_str(), "verbose");
+/// assert_eq!(s.trim_start_matches(|c| c == '-').as_str(), "verbose");
+/// # }
+/// ```
+pub trait Pattern: Sized + sealed::Sealed {
+    #[doc(hidden)]
+    fn __strip_prefix_of(self, s: &str) -> Option<&str>;
+
+    #[doc(hidden)]
+    fn __trim_start_matches_of(self, s: &str) -> &str;
+
+    #[doc(hidden)]
+    fn __split_once_of(self, s: &str) -> Option<(&str, &str)>;
+
+    #[doc(hidden)]
+    fn __rsplit_once_of(self, s: &str) -> Option<(&str, &str)>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_pattern {
+    ($(impl[$($g:tt)*] for $t:ty $(where $w:ty: $b:path)*;)+) => { $(
+        impl<$($g)*> sealed::Sealed for $t $(where $w: $b)* {}
+
+        impl<$($g)*> Pattern for $t $(where $w: $b)* {
+            #[inline]
+            fn __strip_prefix_of(self, s: &str) -> Option<&str> {
+                s.strip_prefix(self)
+            }
+
+            #[inline]
+            fn __trim_start_matches_of(self, s: &str) -> &str {
+                s.trim_start_matches(self)
+            }
+
+            #[inline]
+            fn __split_once_of(self, s: &str) -> Option<(&str, &str)> {
+                s.split_once(self)
+            }
+
+            #[inline]
+            fn __rsplit_once_of(self, s: &str) -> Option<(&str, &str)> {
+                s.rsplit_once(self)
+            }
+        }
+    )+ }
+}
+
+#[cfg(feature = "std")]
+impl_pattern! {
+    impl['a] for &'a String;
+}
+
+impl_pattern! {
+    impl[] for char;
+    impl['a] for &'a str;
+    impl['a, 'b] for &'a &'b str;
+    impl['a] for &'a [char];
+    impl[const N: usize] for [char; N];
+    impl['a, const N: usize] for &'a [char; N];
+    impl[F] for F where F: FnMut(char) -> bool;
+}
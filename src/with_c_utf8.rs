@@ -0,0 +1,98 @@
+use core::mem::MaybeUninit;
+use core::{ptr, slice};
+
+#[cfg(feature = "std")]
+use c_utf8_buf::CUtf8Buf;
+
+use c_utf8::CUtf8;
+use error::Error;
+
+/// The size of the stack buffer used by [`try_with_c_utf8`] and
+/// [`with_c_utf8`], including the nul terminator.
+///
+/// [`try_with_c_utf8`]: fn.try_with_c_utf8.html
+/// [`with_c_utf8`]:     fn.with_c_utf8.html
+pub const STACK_BUF_LEN: usize = 384;
+
+/// Calls `f` with `s` as a C string, copying it into a stack buffer if it is
+/// not already nul-terminated.
+///
+/// This avoids allocating for short strings and is available without the
+/// `std` feature.
+///
+/// # Errors
+///
+/// Returns
+/// [`Error::CapacityExceeded`](enum.Error.html#variant.CapacityExceeded)
+/// without calling `f` if `s` needs to be copied but does not fit within
+/// [`STACK_BUF_LEN`](constant.STACK_BUF_LEN.html) bytes with its terminator.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::{c_char, try_with_c_utf8, Error};
+///
+/// extern "C" {
+///     fn strlen(s: *const c_char) -> usize;
+/// }
+///
+/// let len = try_with_c_utf8("eth0", |c| unsafe { strlen(c.as_ptr()) });
+/// assert_eq!(len, Ok(4));
+///
+/// let long = "x".repeat(1000);
+/// let err = try_with_c_utf8(&long, |c| c.len()).unwrap_err();
+/// assert!(matches!(err, Error::CapacityExceeded(_)));
+/// ```
+pub fn try_with_c_utf8<R, F>(s: &str, f: F) -> Result<R, Error>
+    where F: FnOnce(&CUtf8) -> R
+{
+    with_stack_buf(s, f).map_err(|_| Error::CapacityExceeded(STACK_BUF_LEN - 1))
+}
+
+/// Calls `f` with `s` as a C string if it is nul-terminated or fits within a
+/// stack buffer, or hands back `f` otherwise.
+fn with_stack_buf<R, F>(s: &str, f: F) -> Result<R, F>
+    where F: FnOnce(&CUtf8) -> R
+{
+    if let Ok(c) = CUtf8::from_str(s) {
+        return Ok(f(c));
+    }
+    if s.len() >= STACK_BUF_LEN {
+        return Err(f);
+    }
+
+    let mut buf = MaybeUninit::<[u8; STACK_BUF_LEN]>::uninit();
+    let buf = buf.as_mut_ptr() as *mut u8;
+    unsafe {
+        ptr::copy_nonoverlapping(s.as_ptr(), buf, s.len());
+        *buf.add(s.len()) = 0;
+
+        let bytes = slice::from_raw_parts(buf, s.len() + 1);
+        Ok(f(CUtf8::from_bytes_unchecked(bytes)))
+    }
+}
+
+/// Calls `f` with `s` as a C string, copying it into a stack buffer if it is
+/// short or into a new [`CUtf8Buf`](struct.CUtf8Buf.html) otherwise.
+///
+/// Nothing is copied if `s` is already nul-terminated.
+///
+/// # Examples
+///
+/// ```
+/// use c_utf8::with_c_utf8;
+///
+/// let name = String::from("wlan0");
+/// let bytes = with_c_utf8(&name, |c| c.as_bytes_with_nul().len());
+///
+/// assert_eq!(bytes, 6);
+/// ```
+#[cfg(feature = "std")]
+pub fn with_c_utf8<R, F>(s: &str, f: F) -> R
+    where F: FnOnce(&CUtf8) -> R
+{
+    match with_stack_buf(s, f) {
+        Ok(r) => r,
+        Err(f) => f(&CUtf8Buf::from(s)),
+    }
+}